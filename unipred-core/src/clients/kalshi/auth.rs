@@ -1,7 +1,11 @@
 use super::Kalshi;
 use super::kalshi_error::*;
 
-use rsa::{pkcs1::DecodeRsaPrivateKey, RsaPrivateKey};
+use rsa::{
+    pkcs1::DecodeRsaPrivateKey,
+    pkcs8::DecodePrivateKey,
+    RsaPrivateKey,
+};
 use serde::{Deserialize, Serialize};
 use super::crypto::sign_pss_text;
 use std::fs;
@@ -76,7 +80,28 @@ impl<'a> Kalshi {
         private_key_path: &str,
     ) -> Result<(), KalshiError> {
         let pem_str = fs::read_to_string(private_key_path)?;
-        let private_key = RsaPrivateKey::from_pkcs1_pem(&pem_str)?;
+        self.login_apikey_from_pem(key_id, &pem_str).await
+    }
+
+    /// Asynchronously authenticates a user with the Kalshi exchange using an API key, taking
+    /// the PEM text directly rather than a file path. This is what lets the key arrive from
+    /// an environment variable or secret manager instead of sitting on disk, which matters in
+    /// containerized/serverless deployments.
+    ///
+    /// Tries PKCS#1 first (the format Kalshi's own key-generation docs produce), then falls
+    /// back to PKCS#8 for keys minted by other tooling (e.g. `openssl genpkey`).
+    ///
+    /// # Arguments
+    /// * `key_id` - A string slice representing the API key ID.
+    /// * `pem` - The PEM-encoded private key text (PKCS#1 or PKCS#8).
+    ///
+    /// # Returns
+    /// - `Ok(())`: Empty result indicating successful authentication setup.
+    /// - `Err(KalshiError)`: Error if the PEM is neither valid PKCS#1 nor PKCS#8.
+    pub async fn login_apikey_from_pem(&mut self, key_id: &str, pem: &str) -> Result<(), KalshiError> {
+        let private_key = RsaPrivateKey::from_pkcs1_pem(pem)
+            .or_else(|_| RsaPrivateKey::from_pkcs8_pem(pem))
+            .map_err(|e| KalshiError::CryptoError(format!("Failed to parse private key as PKCS#1 or PKCS#8: {}", e)))?;
 
         self.private_key = Some(Arc::new(private_key));
         self.api_key_id = Some(key_id.to_string());
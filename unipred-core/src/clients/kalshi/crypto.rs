@@ -1,5 +1,11 @@
 use base64::{Engine as _, engine::general_purpose};
-use rsa::{RsaPrivateKey, pss::BlindedSigningKey, signature::{RandomizedSigner, SignatureEncoding}};
+use rsa::{
+    RsaPrivateKey, RsaPublicKey,
+    pkcs1::{EncodeRsaPrivateKey, LineEnding},
+    pkcs8::EncodePublicKey,
+    pss::{BlindedSigningKey, VerifyingKey},
+    signature::{RandomizedSigner, SignatureEncoding, Verifier},
+};
 use sha2::Sha256;
 use rand::rngs::OsRng;
 
@@ -35,6 +41,49 @@ pub fn sign_pss_text(private_key: &RsaPrivateKey, text: &str) -> Result<String,
     Ok(general_purpose::STANDARD.encode(&signature))
 }
 
+/// Generate a fresh RSA keypair suitable for registering a Kalshi API key, returning the
+/// private key alongside its PKCS#1 PEM encoding (the same format `login_apikey` reads back
+/// from disk).
+pub fn generate_api_keypair(bits: usize) -> Result<(RsaPrivateKey, String), SignError> {
+    let private_key = RsaPrivateKey::new(&mut OsRng, bits)
+        .map_err(|e| SignError::SigningFailed(format!("key generation failed: {}", e)))?;
+    let pem = export_private_pem(&private_key)?;
+    Ok((private_key, pem))
+}
+
+/// Encode a private key as a PKCS#1 PEM, matching the format `login_apikey` expects on disk.
+pub fn export_private_pem(private_key: &RsaPrivateKey) -> Result<String, SignError> {
+    private_key
+        .to_pkcs1_pem(LineEnding::LF)
+        .map(|pem| pem.to_string())
+        .map_err(|e| SignError::SigningFailed(format!("PEM encoding failed: {}", e)))
+}
+
+/// Derive and encode the public key as an SPKI PEM, suitable for sharing (e.g. to register
+/// the keypair with Kalshi) without exposing the private key.
+pub fn export_public_pem(private_key: &RsaPrivateKey) -> Result<String, SignError> {
+    let public_key = RsaPublicKey::from(private_key);
+    public_key
+        .to_public_key_pem(LineEnding::LF)
+        .map_err(|e| SignError::SigningFailed(format!("PEM encoding failed: {}", e)))
+}
+
+/// Verify an RSA-PSS/SHA-256 signature produced by `sign_pss_text`. `text` must be built the
+/// same way `get_api_key_headers` builds its signed message
+/// (`{timestamp}{METHOD}/trade-api/v2{path}`), and `sig_b64` is the base64 signature to check.
+pub fn verify_pss_text(public_key: &RsaPublicKey, text: &str, sig_b64: &str) -> Result<bool, SignError> {
+    let signature_bytes = general_purpose::STANDARD
+        .decode(sig_b64)
+        .map_err(|e| SignError::SigningFailed(format!("invalid base64 signature: {}", e)))?;
+    let signature = signature_bytes
+        .as_slice()
+        .try_into()
+        .map_err(|e| SignError::SigningFailed(format!("invalid signature bytes: {}", e)))?;
+
+    let verifying_key = VerifyingKey::<Sha256>::new(public_key.clone());
+    Ok(verifying_key.verify(text.as_bytes(), &signature).is_ok())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -78,4 +127,33 @@ mod tests {
         // Different messages should produce different signatures
         assert_ne!(signature1, signature2);
     }
+
+    #[test]
+    fn test_generate_api_keypair_round_trips_through_pem() {
+        let (private_key, pem) = generate_api_keypair(2048).unwrap();
+        assert!(pem.contains("BEGIN RSA PRIVATE KEY"));
+
+        let reparsed: RsaPrivateKey = rsa::pkcs1::DecodeRsaPrivateKey::from_pkcs1_pem(&pem).unwrap();
+        assert_eq!(private_key.to_pkcs1_pem(LineEnding::LF).unwrap().to_string(), rsa::pkcs1::EncodeRsaPrivateKey::to_pkcs1_pem(&reparsed, LineEnding::LF).unwrap().to_string());
+    }
+
+    #[test]
+    fn test_export_public_pem() {
+        let private_key = get_test_key();
+        let pem = export_public_pem(private_key).unwrap();
+        assert!(pem.contains("BEGIN PUBLIC KEY"));
+    }
+
+    #[test]
+    fn test_verify_pss_text_matches_sign_pss_text() {
+        let private_key = get_test_key();
+        let public_key = RsaPublicKey::from(private_key);
+
+        // Mirrors the exact message construction in `get_api_key_headers`.
+        let text = "1700000000000GET/trade-api/v2/portfolio/balance";
+        let signature = sign_pss_text(private_key, text).unwrap();
+
+        assert!(verify_pss_text(&public_key, text, &signature).unwrap());
+        assert!(!verify_pss_text(&public_key, "tampered", &signature).unwrap());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,65 @@
+use super::kalshi_error::KalshiError;
+use super::Kalshi;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+
+/// One segment of an endpoint's URL path -- either a fixed literal (`"portfolio"`) or a
+/// value interpolated at call time (an order ID, a ticker). Kept separate from a plain
+/// `format!` so `path()` reads as the shape of the route rather than a string template.
+pub enum Segment {
+    Literal(&'static str),
+    Value(String),
+}
+
+impl Segment {
+    fn as_str(&self) -> &str {
+        match self {
+            Segment::Literal(s) => s,
+            Segment::Value(s) => s,
+        }
+    }
+}
+
+/// A single Kalshi REST endpoint, declared as data (method + path + query + body) rather than
+/// hand-assembled at each call site with `build_url`/`build_url_with_params`. Implement this
+/// for a small request struct and dispatch it through `Kalshi::call`; adding an endpoint then
+/// becomes one struct plus a trait impl instead of bespoke per-method plumbing.
+pub trait Endpoint {
+    type Response: DeserializeOwned;
+
+    fn method(&self) -> Method;
+    fn path(&self) -> Vec<Segment>;
+
+    /// Query string parameters; empty by default since most endpoints don't need any.
+    fn query(&self) -> Vec<(&str, String)> {
+        Vec::new()
+    }
+
+    /// JSON request body for write verbs (`POST`/`PUT`); `None` for `GET`/`DELETE`.
+    fn body(&self) -> Option<serde_json::Value> {
+        None
+    }
+}
+
+impl Kalshi {
+    /// Builds the URL from `endpoint.path()`/`query()`, then sends it through
+    /// `execute_with_retry` (auth injection, rate limiting, and retry-with-backoff all
+    /// happen there exactly as they do for the hand-rolled `http_*` calls), deserializing
+    /// the response as `E::Response`.
+    pub async fn call<E: Endpoint>(&self, endpoint: &E) -> Result<E::Response, KalshiError> {
+        let path = format!(
+            "/{}",
+            endpoint.path().iter().map(Segment::as_str).collect::<Vec<_>>().join("/")
+        );
+
+        let query = endpoint.query();
+        let url = if query.is_empty() {
+            self.build_url(&path)?
+        } else {
+            self.build_url_with_params(&path, query)?
+        };
+
+        let body = endpoint.body();
+        self.execute_with_retry(endpoint.method(), &url, body.as_ref()).await
+    }
+}
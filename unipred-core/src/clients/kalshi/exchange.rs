@@ -1,7 +1,37 @@
 use super::Kalshi;
+use super::endpoint::{Endpoint, Segment};
 use super::kalshi_error::*;
+use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
+struct GetExchangeStatus;
+
+impl Endpoint for GetExchangeStatus {
+    type Response = ExchangeStatus;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> Vec<Segment> {
+        vec![Segment::Literal("exchange"), Segment::Literal("status")]
+    }
+}
+
+struct GetExchangeSchedule;
+
+impl Endpoint for GetExchangeSchedule {
+    type Response = ExchangeScheduleResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> Vec<Segment> {
+        vec![Segment::Literal("exchange"), Segment::Literal("schedule")]
+    }
+}
+
 impl Kalshi {
     /// Asynchronously retrieves the current status of the exchange.
     ///
@@ -16,9 +46,7 @@ impl Kalshi {
     /// kalshi_instance.get_exchange_status().await.unwrap();
     /// ```
     pub async fn get_exchange_status(&self) -> Result<ExchangeStatus, KalshiError> {
-        let url = self.build_url("/exchange/status")?;
-        let result: ExchangeStatus = self.http_get(url).await?;
-        Ok(result)
+        self.call(&GetExchangeStatus).await
     }
 
     /// Asynchronously retrieves the exchange's trading schedule.
@@ -34,8 +62,7 @@ impl Kalshi {
     /// kalshi_instance.get_exchange_schedule().await.unwrap();
     /// ```
     pub async fn get_exchange_schedule(&self) -> Result<ExchangeScheduleStandard, KalshiError> {
-        let url = self.build_url("/exchange/schedule")?;
-        let result: ExchangeScheduleResponse = self.http_get(url).await?;
+        let result = self.call(&GetExchangeSchedule).await?;
         Ok(result.schedule)
     }
 }
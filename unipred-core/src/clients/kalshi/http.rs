@@ -1,34 +1,112 @@
 use super::Kalshi;
 use super::kalshi_error::KalshiError;
+use super::rate_limiter::{with_jitter, RequestWeight};
 use reqwest::{Method, Url};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
+use std::time::Duration;
+
+/// Requests that are still failing after this many attempts (the initial try plus retries)
+/// are surfaced to the caller instead of retried further.
+const MAX_ATTEMPTS: u32 = 5;
+
+fn weight_of(method: &Method) -> RequestWeight {
+    match *method {
+        Method::GET | Method::HEAD => RequestWeight::Read,
+        _ => RequestWeight::Write,
+    }
+}
+
+/// Decides whether `error` is worth retrying and, if so, how long to wait first: honors
+/// `Retry-After` on 429s, exponential backoff with jitter on 429 always and on 5xx for
+/// idempotent methods only, and gives up on anything else (auth failures, 4xx validation
+/// errors, etc. won't succeed on retry).
+///
+/// 5xx on a non-idempotent method (`POST`/`PUT`, e.g. `place_order`) is never retried: a 5xx
+/// doesn't tell us whether the write landed before failing, and Kalshi orders carry no
+/// `client_order_id` to dedupe against, so retrying here risks submitting a duplicate order.
+fn backoff_for(error: &KalshiError, attempt: u32, method: &Method) -> Option<Duration> {
+    let exp_backoff = Duration::from_secs(2u64.pow(attempt));
+    let idempotent = matches!(*method, Method::GET | Method::HEAD | Method::DELETE);
+
+    match error {
+        KalshiError::RateLimited { retry_after } => {
+            Some(with_jitter(retry_after.map(Duration::from_secs).unwrap_or(exp_backoff)))
+        }
+        KalshiError::Api { status, .. } if *status >= 500 && idempotent => Some(with_jitter(exp_backoff)),
+        _ => None,
+    }
+}
 
 impl Kalshi {
-    /// Helper to add auth headers and create a request builder.
+    /// Helper to add auth headers and create a request builder. Does *not* apply rate
+    /// limiting -- that happens once per attempt in `execute_with_retry`, since a retried
+    /// request needs to re-acquire from the governor just like a fresh one.
     fn prepare_request(&self, method: Method, url: &Url) -> Result<reqwest::RequestBuilder, KalshiError> {
         let mut req = self.client.request(method.clone(), url.clone());
-        
+
         if self.has_api_key() {
              let mut path_and_query = url.path().to_string();
              if let Some(query) = url.query() {
                  path_and_query.push('?');
                  path_and_query.push_str(query);
              }
-             
+
              let headers = self.get_api_key_headers(method.as_str(), &path_and_query)?;
              req = req.headers(headers);
         } else if let Some(token) = self.get_user_token() {
             req = req.header("Authorization", token);
         }
-        
+
         Ok(req)
     }
 
+    /// Awaits the rate limiter, sends the request, and retries on 429 (and on 5xx for
+    /// idempotent methods only; see `backoff_for`) with exponential backoff + jitter (honoring
+    /// `Retry-After` when Kalshi sends one), up to `MAX_ATTEMPTS`.
+    /// `body` is serialized once up front so every retry attempt resends the same payload.
+    pub(super) async fn execute_with_retry<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        url: &Url,
+        body: Option<&serde_json::Value>,
+    ) -> Result<T, KalshiError> {
+        let weight = weight_of(&method);
+
+        for attempt in 0.. {
+            self.rate_limiter.acquire(weight).await;
+
+            let mut req = self.prepare_request(method.clone(), url)?;
+            if let Some(body) = body {
+                req = req.json(body);
+            }
+            let resp = req.send().await?;
+
+            match self.process_response::<T>(method.as_str(), url, resp).await {
+                Ok(v) => return Ok(v),
+                Err(e) => {
+                    if attempt + 1 >= MAX_ATTEMPTS {
+                        return Err(e);
+                    }
+                    match backoff_for(&e, attempt, &method) {
+                        Some(wait) => {
+                            eprintln!(
+                                "{} {} failed ({}), retrying in {:.1}s (attempt {}/{})",
+                                method, url, e, wait.as_secs_f64(), attempt + 1, MAX_ATTEMPTS
+                            );
+                            tokio::time::sleep(wait).await;
+                        }
+                        None => return Err(e),
+                    }
+                }
+            }
+        }
+
+        unreachable!("loop only exits via return")
+    }
+
     pub async fn http_get<T: DeserializeOwned>(&self, url: Url) -> Result<T, KalshiError> {
-        let req = self.prepare_request(Method::GET, &url)?;
-        let resp = req.send().await?;
-        self.process_response("GET", &url, resp).await
+        self.execute_with_retry(Method::GET, &url, None).await
     }
 
     pub async fn http_post<B, T>(&self, url: Url, body: &B) -> Result<T, KalshiError>
@@ -36,27 +114,23 @@ impl Kalshi {
         B: Serialize + ?Sized,
         T: DeserializeOwned,
     {
-        let mut req = self.prepare_request(Method::POST, &url)?;
-        req = req.json(body);
-        let resp = req.send().await?;
-        self.process_response::<T>("POST", &url, resp).await
+        let body = serde_json::to_value(body)
+            .map_err(|e| KalshiError::InternalError(format!("Failed to serialize request body: {}", e)))?;
+        self.execute_with_retry(Method::POST, &url, Some(&body)).await
     }
 
     pub async fn http_delete<T: DeserializeOwned>(&self, url: Url) -> Result<T, KalshiError> {
-        let req = self.prepare_request(Method::DELETE, &url)?;
-        let resp = req.send().await?;
-        self.process_response::<T>("DELETE", &url, resp).await
+        self.execute_with_retry(Method::DELETE, &url, None).await
     }
-    
+
     pub async fn http_put<B, T>(&self, url: Url, body: &B) -> Result<T, KalshiError>
     where
         B: Serialize + ?Sized,
         T: DeserializeOwned,
     {
-        let mut req = self.prepare_request(Method::PUT, &url)?;
-        req = req.json(body);
-        let resp = req.send().await?;
-        self.process_response::<T>("PUT", &url, resp).await
+        let body = serde_json::to_value(body)
+            .map_err(|e| KalshiError::InternalError(format!("Failed to serialize request body: {}", e)))?;
+        self.execute_with_retry(Method::PUT, &url, Some(&body)).await
     }
 
     async fn process_response<T: DeserializeOwned>(
@@ -66,17 +140,18 @@ impl Kalshi {
         resp: reqwest::Response,
     ) -> Result<T, KalshiError> {
         let status = resp.status();
+        let retry_after = resp
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok());
         let bytes = resp.bytes().await.map_err(|e| KalshiError::InternalError(e.to_string()))?;
 
         if !status.is_success() {
              let body_str = String::from_utf8_lossy(&bytes);
              eprintln!("HTTP {} {} failed: status={}, body={}", method, url, status, body_str);
-             
-             return Err(KalshiError::InternalError(format!(
-                "Non-success status {}. Body: {}",
-                status,
-                body_str
-            )));
+
+             return Err(KalshiError::from_response(status, retry_after, &bytes));
         }
 
         serde_json::from_slice::<T>(&bytes).map_err(|e| {
@@ -0,0 +1,92 @@
+use serde::Deserialize;
+use thiserror::Error;
+
+/// Kalshi's JSON error envelope, returned on most non-2xx responses:
+/// `{"error": {"code": "...", "message": "..."}}`.
+#[derive(Debug, Deserialize)]
+struct ErrorEnvelope {
+    error: ErrorBody,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorBody {
+    code: String,
+    message: String,
+}
+
+/// Typed errors from the Kalshi client, parsed out of HTTP status + JSON error body in
+/// `process_response` so callers can branch on e.g. rate limiting instead of matching on a
+/// stringified response.
+#[derive(Debug, Error)]
+pub enum KalshiError {
+    #[error("not authenticated: call login() or login_apikey_from_pem() first")]
+    NotAuthenticated,
+
+    #[error("unauthorized: {0}")]
+    Unauthorized(String),
+
+    #[error("rate limited{}", .retry_after.map(|s| format!(" (retry after {}s)", s)).unwrap_or_default())]
+    RateLimited { retry_after: Option<u64> },
+
+    #[error("not found: {0}")]
+    NotFound(String),
+
+    #[error("insufficient balance: {0}")]
+    InsufficientBalance(String),
+
+    #[error("market closed: {0}")]
+    MarketClosed(String),
+
+    /// Fallback for any error response that parsed as `ErrorEnvelope` but didn't match a
+    /// well-known code, or that didn't carry the envelope at all (`code` is then `None` and
+    /// `message` is the raw body).
+    #[error("kalshi api error (status={status}, code={code:?}): {message}")]
+    Api {
+        status: u16,
+        code: Option<String>,
+        message: String,
+    },
+
+    #[error("crypto error: {0}")]
+    CryptoError(String),
+
+    #[error("request failed: {0}")]
+    RequestError(#[from] reqwest::Error),
+
+    #[error("internal error: {0}")]
+    InternalError(String),
+}
+
+impl KalshiError {
+    /// Parses a non-success HTTP response into a typed `KalshiError`, preferring Kalshi's
+    /// `{"error": {"code", "message"}}` envelope and falling back to the raw body when the
+    /// response isn't JSON (e.g. an upstream proxy error page). When the envelope is absent,
+    /// `body` is kept verbatim as the message so callers that just log the error don't lose
+    /// information; when it parses, only the envelope's `message` is kept. `retry_after`
+    /// comes from the response's `Retry-After` header, since Kalshi doesn't echo it in the body.
+    pub fn from_response(status: reqwest::StatusCode, retry_after: Option<u64>, body: &[u8]) -> Self {
+        let envelope = serde_json::from_slice::<ErrorEnvelope>(body).ok();
+        let code = envelope.as_ref().map(|e| e.error.code.clone());
+        let message = envelope
+            .as_ref()
+            .map(|e| e.error.message.clone())
+            .unwrap_or_else(|| String::from_utf8_lossy(body).to_string());
+
+        match (status.as_u16(), code.as_deref()) {
+            (401, _) | (_, Some("unauthorized")) => KalshiError::Unauthorized(message),
+            (429, _) | (_, Some("rate_limit_exceeded")) => {
+                KalshiError::RateLimited { retry_after }
+            }
+            (404, _) | (_, Some("not_found")) => KalshiError::NotFound(message),
+            (_, Some("insufficient_balance")) => KalshiError::InsufficientBalance(message),
+            (_, Some("market_not_active")) | (_, Some("market_closed")) => {
+                KalshiError::MarketClosed(message)
+            }
+            _ => KalshiError::Api {
+                status: status.as_u16(),
+                code,
+                message,
+            },
+        }
+    }
+}
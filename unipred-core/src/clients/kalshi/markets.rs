@@ -0,0 +1,59 @@
+use super::kalshi_error::KalshiError;
+use super::Kalshi;
+use futures::stream::Stream;
+use serde::Deserialize;
+
+/// A single market as Kalshi's `/markets` endpoint represents it on the wire -- distinct from
+/// `crate::proto::FetchedMarket`, the unified DTO `commands::markets::FetchMarkets` maps this
+/// into once a page is fetched.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RawMarket {
+    pub ticker: String,
+    pub title: String,
+    pub subtitle: String,
+    pub status: String,
+    pub yes_sub_title: String,
+    pub no_sub_title: String,
+    pub open_time: String,
+    pub close_time: String,
+    pub volume: i64,
+    pub liquidity: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct MarketsPage {
+    markets: Vec<RawMarket>,
+    cursor: String,
+}
+
+impl Kalshi {
+    /// Streams every market matching `status` (or all statuses if `None`), transparently
+    /// paging through `/markets` via `paginate` so callers don't manage cursors by hand:
+    ///
+    /// ```ignore
+    /// let mut markets = kalshi_instance.markets_stream(Some("open".to_string()), 100);
+    /// while let Some(market) = markets.next().await {
+    ///     let market = market?;
+    /// }
+    /// ```
+    pub fn markets_stream<'a>(
+        &'a self,
+        status: Option<String>,
+        page_limit: usize,
+    ) -> impl Stream<Item = Result<RawMarket, KalshiError>> + 'a {
+        let mut params = Vec::new();
+        if let Some(status) = status {
+            params.push(("status".to_string(), status));
+        }
+
+        self.paginate::<MarketsPage, RawMarket, _>(
+            "/markets".to_string(),
+            params,
+            page_limit,
+            |page: MarketsPage| {
+                let cursor = (!page.cursor.is_empty()).then_some(page.cursor);
+                (page.markets, cursor)
+            },
+        )
+    }
+}
@@ -0,0 +1,210 @@
+use super::Kalshi;
+use super::endpoint::{Endpoint, Segment};
+use super::kalshi_error::*;
+use crate::clients::kalshi::tick::Tick;
+use crate::domain::{OrderSide, OrderType, TimeInForce};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+struct PlaceOrder(CreateOrderPayload);
+
+impl Endpoint for PlaceOrder {
+    type Response = CreateOrderResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> Vec<Segment> {
+        vec![Segment::Literal("portfolio"), Segment::Literal("orders")]
+    }
+
+    fn body(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.0).ok()
+    }
+}
+
+struct CancelOrder<'a>(&'a str);
+
+impl<'a> Endpoint for CancelOrder<'a> {
+    type Response = serde_json::Value;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn path(&self) -> Vec<Segment> {
+        vec![
+            Segment::Literal("portfolio"),
+            Segment::Literal("orders"),
+            Segment::Value(self.0.to_string()),
+        ]
+    }
+}
+
+struct GetOpenOrders<'a>(Option<&'a str>);
+
+impl<'a> Endpoint for GetOpenOrders<'a> {
+    type Response = OrdersResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> Vec<Segment> {
+        vec![Segment::Literal("portfolio"), Segment::Literal("orders")]
+    }
+
+    fn query(&self) -> Vec<(&str, String)> {
+        let mut params = vec![("status", "resting".to_string())];
+        if let Some(ticker) = self.0 {
+            params.push(("ticker", ticker.to_string()));
+        }
+        params
+    }
+}
+
+struct GetPositions;
+
+impl Endpoint for GetPositions {
+    type Response = PositionsResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> Vec<Segment> {
+        vec![Segment::Literal("portfolio"), Segment::Literal("positions")]
+    }
+}
+
+impl Kalshi {
+    /// Asynchronously places a limit or market order on the Kalshi exchange.
+    ///
+    /// Kalshi prices orders in whole cents, so `price` is converted from `Tick`s to cents (any
+    /// sub-cent remainder is dropped) and sent as `yes_price` or `no_price` depending on
+    /// `side`. A market order sends the caller's `price` the same way; no best-available price
+    /// is substituted for it.
+    ///
+    /// # Arguments
+    /// * `ticker` - The market ticker to trade.
+    /// * `side` - `Yes` or `No`.
+    /// * `price` - Limit price in `Tick`s (ignored for market orders).
+    /// * `size` - Number of contracts.
+    /// * `order_type` - `Limit` or `Market`.
+    /// * `tif` - Time-in-force.
+    ///
+    /// # Returns
+    /// - `Ok(OrderAck)`: The exchange's order acknowledgement, including its assigned ID.
+    /// - `Err(KalshiError)`: Error in case of a failure in the HTTP request or response parsing.
+    pub async fn place_order(
+        &self,
+        ticker: &str,
+        side: OrderSide,
+        price: Tick,
+        size: i64,
+        order_type: OrderType,
+        tif: TimeInForce,
+    ) -> Result<OrderAck, KalshiError> {
+        let payload = CreateOrderPayload {
+            ticker: ticker.to_string(),
+            side: match side {
+                OrderSide::Yes => "yes",
+                OrderSide::No => "no",
+            },
+            action: "buy",
+            order_type: match order_type {
+                OrderType::Limit => "limit",
+                OrderType::Market => "market",
+            },
+            // 100 ticks ($0.0001 each) per cent.
+            yes_price: if side == OrderSide::Yes { Some(price.0 / 100) } else { None },
+            no_price: if side == OrderSide::No { Some(price.0 / 100) } else { None },
+            count: size,
+            time_in_force: match tif {
+                TimeInForce::GoodTilCanceled => "good_till_cancelled",
+                TimeInForce::ImmediateOrCancel => "immediate_or_cancel",
+                TimeInForce::FillOrKill => "fill_or_kill",
+            },
+        };
+
+        let result = self.call(&PlaceOrder(payload)).await?;
+        Ok(result.order)
+    }
+
+    /// Asynchronously cancels a resting order by its exchange-assigned ID.
+    ///
+    /// # Returns
+    /// - `Ok(())`: The order was canceled (or had already filled/canceled).
+    /// - `Err(KalshiError)`: Error in case of a failure in the HTTP request.
+    pub async fn cancel_order(&self, order_id: &str) -> Result<(), KalshiError> {
+        self.call(&CancelOrder(order_id)).await?;
+        Ok(())
+    }
+
+    /// Asynchronously lists this account's resting (unfilled) orders, optionally scoped to a
+    /// single ticker.
+    pub async fn get_open_orders(&self, ticker: Option<&str>) -> Result<Vec<OrderAck>, KalshiError> {
+        let result = self.call(&GetOpenOrders(ticker)).await?;
+        Ok(result.orders)
+    }
+
+    /// Asynchronously lists this account's open positions across all markets.
+    pub async fn get_positions(&self) -> Result<Vec<KalshiPosition>, KalshiError> {
+        let result = self.call(&GetPositions).await?;
+        Ok(result.market_positions)
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct CreateOrderPayload {
+    ticker: String,
+    side: &'static str,
+    action: &'static str,
+    #[serde(rename = "type")]
+    order_type: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    yes_price: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    no_price: Option<i32>,
+    count: i64,
+    time_in_force: &'static str,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CreateOrderResponse {
+    order: OrderAck,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct OrdersResponse {
+    orders: Vec<OrderAck>,
+}
+
+/// The exchange's representation of an order, whether just placed or fetched back via
+/// `get_open_orders`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderAck {
+    pub order_id: String,
+    pub ticker: String,
+    pub side: String,
+    pub status: String,
+    #[serde(default)]
+    pub yes_price: Option<i32>,
+    #[serde(default)]
+    pub no_price: Option<i32>,
+    pub remaining_count: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PositionsResponse {
+    market_positions: Vec<KalshiPosition>,
+}
+
+/// A single market position on this account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KalshiPosition {
+    pub ticker: String,
+    pub position: i64,
+    pub market_exposure: i64,
+}
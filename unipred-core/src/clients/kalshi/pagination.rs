@@ -0,0 +1,84 @@
+use super::kalshi_error::KalshiError;
+use super::Kalshi;
+use futures::stream::{self, Stream};
+use serde::de::DeserializeOwned;
+use std::collections::VecDeque;
+
+/// Cursor-pagination state threaded through `paginate`'s `stream::unfold`: either there's
+/// another page to fetch (possibly the first, with no cursor yet) or the stream is exhausted.
+enum PageCursor {
+    Next(Option<String>),
+    Done,
+}
+
+impl Kalshi {
+    /// Streams every item across all pages of a Kalshi list endpoint at `base_path`, so
+    /// callers consume items one at a time instead of looping `http_get` and threading a
+    /// cursor themselves. `params` are held fixed on every request (e.g. `status`,
+    /// `event_ticker`); `page_limit` becomes the `limit` query parameter; `extract` pulls
+    /// `(items, next_cursor)` out of each raw page response `R`. Every page fetch goes
+    /// through `http_get`, so rate limiting and retry-with-backoff apply exactly as they do
+    /// for any other call -- a large historical pull backs off the same way a single request
+    /// would.
+    pub fn paginate<'a, R, T, F>(
+        &'a self,
+        base_path: String,
+        params: Vec<(String, String)>,
+        page_limit: usize,
+        extract: F,
+    ) -> impl Stream<Item = Result<T, KalshiError>> + 'a
+    where
+        R: DeserializeOwned + 'a,
+        T: 'a,
+        F: Fn(R) -> (Vec<T>, Option<String>) + 'a,
+    {
+        stream::unfold(
+            (PageCursor::Next(None), VecDeque::<T>::new()),
+            move |(mut state, mut buffer)| {
+                let base_path = base_path.clone();
+                let params = params.clone();
+                let extract = &extract;
+                async move {
+                    loop {
+                        if let Some(item) = buffer.pop_front() {
+                            return Some((Ok(item), (state, buffer)));
+                        }
+
+                        let cursor = match &state {
+                            PageCursor::Next(cursor) => cursor.clone(),
+                            PageCursor::Done => return None,
+                        };
+
+                        let mut page_params = params.clone();
+                        if let Some(c) = &cursor {
+                            page_params.push(("cursor".to_string(), c.clone()));
+                        }
+                        page_params.push(("limit".to_string(), page_limit.to_string()));
+
+                        let url = match self.build_url_with_params(
+                            &base_path,
+                            page_params.iter().map(|(k, v)| (k.as_str(), v.clone())).collect(),
+                        ) {
+                            Ok(url) => url,
+                            Err(e) => return Some((Err(e), (PageCursor::Done, buffer))),
+                        };
+
+                        match self.http_get::<R>(url).await {
+                            Ok(page) => {
+                                let (items, next_cursor) = extract(page);
+                                buffer.extend(items);
+                                state = match next_cursor {
+                                    Some(c) if !c.is_empty() && Some(c.clone()) != cursor => {
+                                        PageCursor::Next(Some(c))
+                                    }
+                                    _ => PageCursor::Done,
+                                };
+                            }
+                            Err(e) => return Some((Err(e), (PageCursor::Done, buffer))),
+                        }
+                    }
+                }
+            },
+        )
+    }
+}
@@ -0,0 +1,79 @@
+use rand::Rng;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+/// Whether a request counts as a read or a write against Kalshi's rate limit. Kalshi tiers
+/// cap order placement/cancellation more tightly than market/quote polling, so writes draw
+/// down the shared token bucket faster than reads.
+#[derive(Copy, Clone, Debug)]
+pub enum RequestWeight {
+    Read,
+    Write,
+}
+
+impl RequestWeight {
+    fn cost(self) -> f64 {
+        match self {
+            RequestWeight::Read => 1.0,
+            RequestWeight::Write => 5.0,
+        }
+    }
+}
+
+/// Token-bucket governor shared across every request a `Kalshi` client makes, so bursts of
+/// concurrent `FetchMarkets`/`GetMarketQuote` calls self-throttle to Kalshi's per-tier rate
+/// limit instead of relying solely on reacting to 429s after the fact.
+pub struct RateLimiter {
+    capacity: f64,
+    refill_per_sec: f64,
+    state: Mutex<(f64, Instant)>,
+}
+
+impl RateLimiter {
+    /// `requests_per_sec` is the read-weighted budget; writes consume `RequestWeight::Write`
+    /// tokens from the same bucket, so the effective write rate is lower.
+    pub fn new(requests_per_sec: f64) -> Self {
+        let capacity = requests_per_sec.max(1.0);
+        Self {
+            capacity,
+            refill_per_sec: capacity,
+            state: Mutex::new((capacity, Instant::now())),
+        }
+    }
+
+    /// Blocks until enough tokens are available for a request of the given weight.
+    pub async fn acquire(&self, weight: RequestWeight) {
+        let cost = weight.cost();
+        loop {
+            let wait = {
+                let mut state = self.state.lock().await;
+                let (tokens, last_refill) = &mut *state;
+
+                let now = Instant::now();
+                let elapsed = now.duration_since(*last_refill).as_secs_f64();
+                *tokens = (*tokens + elapsed * self.refill_per_sec).min(self.capacity);
+                *last_refill = now;
+
+                if *tokens >= cost {
+                    *tokens -= cost;
+                    None
+                } else {
+                    let deficit = cost - *tokens;
+                    Some(Duration::from_secs_f64(deficit / self.refill_per_sec))
+                }
+            };
+
+            match wait {
+                None => return,
+                Some(d) => tokio::time::sleep(d).await,
+            }
+        }
+    }
+}
+
+/// Adds up to +/-50% jitter to a backoff duration so that a fleet of clients hitting the same
+/// 429 don't all retry in lockstep.
+pub fn with_jitter(base: Duration) -> Duration {
+    let factor = rand::thread_rng().gen_range(0.5..1.5);
+    Duration::from_secs_f64(base.as_secs_f64() * factor)
+}
@@ -0,0 +1,113 @@
+use super::ClobClient;
+use crate::clients::kalshi::tick::Tick;
+use crate::domain::{OrderSide, OrderType, TimeInForce};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+impl ClobClient {
+    /// Asynchronously places a signed order on Polymarket's CLOB.
+    ///
+    /// Polymarket prices a conditional token in decimal USDC (e.g. `0.5200`) rather than
+    /// Kalshi's integer cents, so `price` is rendered via `Tick::to_dollars_string` to keep
+    /// the two exchanges' order paths using the exact same fixed-point representation.
+    ///
+    /// # Arguments
+    /// * `token_id` - The CLOB token ID (this crate's Polymarket "ticker").
+    /// * `side` - `Yes` maps to a `BUY`, `No` to a `SELL` of the token.
+    /// * `price` - Limit price in `Tick`s (ignored for market orders).
+    /// * `size` - Number of shares.
+    /// * `order_type` - `Limit` or `Market`.
+    /// * `tif` - Time-in-force.
+    ///
+    /// # Returns
+    /// - `Ok(ClobOrderAck)`: The CLOB's order acknowledgement, including its assigned ID.
+    /// - `Err(anyhow::Error)`: Error in case of a failure signing, sending, or parsing the
+    ///   request.
+    pub async fn place_order(
+        &self,
+        token_id: &str,
+        side: OrderSide,
+        price: Tick,
+        size: i64,
+        order_type: OrderType,
+        tif: TimeInForce,
+    ) -> Result<ClobOrderAck> {
+        let payload = ClobOrderPayload {
+            token_id: token_id.to_string(),
+            side: match side {
+                OrderSide::Yes => "BUY",
+                OrderSide::No => "SELL",
+            },
+            price: price.to_dollars_string(),
+            size,
+            order_type: match order_type {
+                OrderType::Limit => "GTC",
+                OrderType::Market => "FOK",
+            },
+            time_in_force: match tif {
+                TimeInForce::GoodTilCanceled => "GTC",
+                TimeInForce::ImmediateOrCancel => "IOC",
+                TimeInForce::FillOrKill => "FOK",
+            },
+        };
+
+        let signed = self.sign_order(&payload)?;
+        let ack: ClobOrderAck = self.http_post("/order", &signed).await?;
+        Ok(ack)
+    }
+
+    /// Asynchronously cancels a resting order by its CLOB-assigned ID.
+    pub async fn cancel_order(&self, order_id: &str) -> Result<()> {
+        let _: serde_json::Value = self
+            .http_delete(&format!("/order/{}", order_id))
+            .await?;
+        Ok(())
+    }
+
+    /// Asynchronously lists this account's resting (unmatched) orders, optionally scoped to a
+    /// single token.
+    pub async fn get_open_orders(&self, token_id: Option<&str>) -> Result<Vec<ClobOrderAck>> {
+        let path = match token_id {
+            Some(t) => format!("/orders?market={}", t),
+            None => "/orders".to_string(),
+        };
+        let orders: Vec<ClobOrderAck> = self.http_get(&path).await?;
+        Ok(orders)
+    }
+
+    /// Asynchronously lists this account's open positions across all tokens.
+    pub async fn get_positions(&self) -> Result<Vec<ClobPosition>> {
+        let positions: Vec<ClobPosition> = self.http_get("/positions").await?;
+        Ok(positions)
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ClobOrderPayload {
+    token_id: String,
+    side: &'static str,
+    price: String,
+    size: i64,
+    order_type: &'static str,
+    time_in_force: &'static str,
+}
+
+/// The CLOB's representation of an order, whether just placed or fetched back via
+/// `get_open_orders`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClobOrderAck {
+    pub order_id: String,
+    pub token_id: String,
+    pub side: String,
+    pub status: String,
+    pub price: String,
+    pub size_matched: i64,
+}
+
+/// A single token position on this account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClobPosition {
+    pub token_id: String,
+    pub size: i64,
+    pub avg_price: String,
+}
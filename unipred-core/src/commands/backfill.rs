@@ -0,0 +1,144 @@
+use super::Command;
+use crate::UnipredCore;
+
+use crate::domain::MarketSource;
+use crate::error::UnipredError;
+use crate::storage::candles::BASE_RESOLUTION_SECS;
+use crate::storage::trades::RawTrade;
+use async_trait::async_trait;
+
+/// Pages an exchange's historical trades endpoint (Kalshi's trades endpoint, Polymarket's
+/// trade history) and writes every raw trade into the `trades` table, keyed by `trade_id`
+/// and stamped with its exchange-reported execution time so a stale/late backfill lands in
+/// the correct bucket.
+///
+/// This is deliberately separate from candle derivation (see `RebuildCandlesFromTrades`):
+/// a backfill failure never blocks re-deriving candles from whatever trades already landed.
+pub struct BackfillTrades {
+    pub ticker: String,
+    pub source: MarketSource,
+    pub start_ts: Option<i64>,
+    pub end_ts: Option<i64>,
+}
+
+impl BackfillTrades {
+    pub fn new(ticker: String, source: MarketSource) -> Self {
+        Self {
+            ticker,
+            source,
+            start_ts: None,
+            end_ts: None,
+        }
+    }
+
+    pub fn with_range(mut self, start_ts: i64, end_ts: i64) -> Self {
+        self.start_ts = Some(start_ts);
+        self.end_ts = Some(end_ts);
+        self
+    }
+}
+
+#[async_trait]
+impl Command for BackfillTrades {
+    type Response = usize;
+
+    async fn execute(&self, core: &UnipredCore) -> Result<usize, UnipredError> {
+        let mut cursor: Option<String> = None;
+        let mut total = 0usize;
+
+        loop {
+            let (trades, next_cursor): (Vec<RawTrade>, Option<String>) = match self.source {
+                MarketSource::Kalshi => {
+                    let (page, next) = core
+                        .kalshi
+                        .get_trades(&self.ticker, cursor.clone(), self.start_ts, self.end_ts)
+                        .await?;
+                    (
+                        page.into_iter()
+                            .map(|t| RawTrade {
+                                ticker: self.ticker.clone(),
+                                source: "Kalshi".to_string(),
+                                trade_id: t.trade_id,
+                                price: t.price,
+                                size: t.size,
+                                side: t.side,
+                                traded_at: t.traded_at,
+                            })
+                            .collect(),
+                        next,
+                    )
+                }
+                MarketSource::Polymarket => {
+                    let (page, next) = core
+                        .polymarket
+                        .get_trade_history(&self.ticker, cursor.clone())
+                        .await?;
+                    (
+                        page.into_iter()
+                            .map(|t| RawTrade {
+                                ticker: self.ticker.clone(),
+                                source: "Polymarket".to_string(),
+                                trade_id: t.trade_id,
+                                price: t.price,
+                                size: t.size,
+                                side: t.side,
+                                traded_at: t.traded_at,
+                            })
+                            .collect(),
+                        next,
+                    )
+                }
+                MarketSource::All => {
+                    return Err(UnipredError::Parse("A backfill must target one exchange, not MarketSource::All".to_string()));
+                }
+                MarketSource::Unknown => {
+                    return Err(UnipredError::Parse("Cannot backfill trades for unknown exchange".to_string()));
+                }
+            };
+
+            if trades.is_empty() {
+                break;
+            }
+
+            total += trades.len();
+            core.duck_store.lock().await.backfill_trades(&trades)?;
+
+            if next_cursor.is_none() {
+                break;
+            }
+            cursor = next_cursor;
+        }
+
+        Ok(total)
+    }
+}
+
+/// Re-derives the `candles` table from whatever is already in `trades`, independent of
+/// `BackfillTrades`. Safe to re-run after a schema change without re-hitting the APIs.
+pub struct RebuildCandlesFromTrades {
+    pub resolution: String,
+    pub resolution_secs: i64,
+}
+
+impl RebuildCandlesFromTrades {
+    pub fn base_resolution() -> Self {
+        Self {
+            resolution: "1m".to_string(),
+            resolution_secs: BASE_RESOLUTION_SECS,
+        }
+    }
+}
+
+#[async_trait]
+impl Command for RebuildCandlesFromTrades {
+    type Response = usize;
+
+    async fn execute(&self, core: &UnipredCore) -> Result<usize, UnipredError> {
+        let count = core
+            .duck_store
+            .lock()
+            .await
+            .rebuild_candles_from_trades(&self.resolution, self.resolution_secs)?;
+        Ok(count)
+    }
+}
@@ -1,10 +1,11 @@
 use super::Command;
 use crate::UnipredCore;
 
-use crate::domain::MarketSource;
+use crate::domain::{CompositeCursor, MarketSource};
 use async_trait::async_trait;
-use anyhow::Result;
+use crate::error::UnipredError;
 use crate::proto::{FetchedEvent, FetchedEventList};
+use std::collections::HashMap;
 
 // TODO: Consider migrating this and FetchMarkets to a Repository pattern eventually.
 // Currently, these commands act as the adapter layer between specific exchange clients
@@ -48,61 +49,148 @@ impl FetchEvents {
     }
 }
 
+impl FetchEvents {
+    async fn fetch_kalshi(&self, core: &UnipredCore, cursor: Option<String>) -> Result<(Vec<FetchedEvent>, Option<String>), UnipredError> {
+        let api_status = if let Some(s) = &self.status {
+             if s == "active" { Some("open".to_string()) } else { Some(s.clone()) }
+        } else {
+            None
+        };
+
+        let (next_cursor, events) = core
+            .kalshi
+            .get_multiple_events(
+                self.limit,
+                cursor,
+                api_status,
+                None,
+                None
+            )
+            .await?;
+
+        let unified_events = events
+            .into_iter()
+            .map(|e| FetchedEvent {
+                ticker: e.event_ticker.clone(),
+                title: e.title,
+                source: "Kalshi".to_string(),
+                description: e.sub_title,
+                start_date: e.strike_date.unwrap_or_default(),
+                end_date: "".to_string(),
+                url: format!("https://kalshi.com/events/{}", e.event_ticker),
+            })
+            .collect();
+
+        Ok((unified_events, next_cursor))
+    }
+
+    async fn fetch_polymarket(&self, core: &UnipredCore, cursor: Option<String>) -> Result<(Vec<FetchedEvent>, Option<String>), UnipredError> {
+        // Polymarket has no dedicated event endpoint: the markets API returns individual
+        // markets grouped by event slug, so we synthesize one FetchedEvent per group.
+        let markets = core
+            .polymarket
+            .get_markets(cursor.as_deref())
+            .await?;
+
+        let mut slug_order: Vec<String> = Vec::new();
+        let mut groups: HashMap<String, Vec<_>> = HashMap::new();
+        for m in markets.data {
+            let slug = m.market_slug.clone();
+            groups.entry(slug.clone()).or_insert_with(|| {
+                slug_order.push(slug.clone());
+                Vec::new()
+            }).push(m);
+        }
+
+        let unified_events = slug_order
+            .into_iter()
+            .map(|slug| {
+                let members = groups.remove(&slug).unwrap_or_default();
+                let representative = &members[0];
+
+                let start_date = members
+                    .iter()
+                    .map(|m| m.game_start_time.clone().unwrap_or_default())
+                    .filter(|d| !d.is_empty())
+                    .min()
+                    .unwrap_or_default();
+                let end_date = members
+                    .iter()
+                    .map(|m| m.end_date_iso.clone().unwrap_or_default())
+                    .filter(|d| !d.is_empty())
+                    .max()
+                    .unwrap_or_default();
+
+                FetchedEvent {
+                    ticker: slug.clone(),
+                    title: representative.question.clone(),
+                    source: "Polymarket".to_string(),
+                    description: representative.description.clone(),
+                    start_date,
+                    end_date,
+                    url: format!("https://polymarket.com/event/{}", slug),
+                }
+            })
+            .collect();
+
+        Ok((unified_events, markets.next_cursor))
+    }
+}
+
 #[async_trait]
 impl Command for FetchEvents {
     type Response = FetchedEventList;
 
-    async fn execute(&self, core: &UnipredCore) -> Result<Self::Response> {
+    async fn execute(&self, core: &UnipredCore) -> Result<Self::Response, UnipredError> {
         let source = self.exchange.unwrap_or(MarketSource::Kalshi);
 
         match source {
             MarketSource::Kalshi => {
-                let api_status = if let Some(s) = &self.status {
-                     if s == "active" { Some("open".to_string()) } else { Some(s.clone()) }
-                } else {
-                    None
-                };
-
-                let (next_cursor, events) = core
-                    .kalshi
-                    .get_multiple_events(
-                        self.limit,
-                        self.cursor.clone(),
-                        api_status,
-                        None,
-                        None
-                    )
-                    .await?;
-
-                let unified_events = events
-                    .into_iter()
-                    .map(|e| FetchedEvent {
-                        ticker: e.event_ticker.clone(),
-                        title: e.title,
-                        source: "Kalshi".to_string(),
-                        description: e.sub_title,
-                        start_date: e.strike_date.unwrap_or_default(),
-                        end_date: "".to_string(),
-                        url: format!("https://kalshi.com/events/{}", e.event_ticker),
-                    })
-                    .collect();
-
+                let (events, next_cursor) = self.fetch_kalshi(core, self.cursor.clone()).await?;
                 Ok(FetchedEventList {
                     cursor: next_cursor.unwrap_or_default(),
-                    events: unified_events,
+                    events,
                 })
             }
             MarketSource::Polymarket => {
-                // Polymarket events fetching not yet implemented in this command
-                // Their "markets" API returns markets which are grouped by event, 
-                // but dedicated event fetching might require a different endpoint or aggregation.
+                let (events, next_cursor) = self.fetch_polymarket(core, self.cursor.clone()).await?;
+                Ok(FetchedEventList {
+                    cursor: next_cursor.unwrap_or_default(),
+                    events,
+                })
+            }
+            MarketSource::All => {
+                let in_cursor = CompositeCursor::decode(self.cursor.as_deref().unwrap_or(""));
+
+                let kalshi_fut = async {
+                    if in_cursor.kalshi_done {
+                        Ok((Vec::new(), None))
+                    } else {
+                        self.fetch_kalshi(core, in_cursor.kalshi.clone()).await
+                    }
+                };
+                let polymarket_fut = async {
+                    if in_cursor.polymarket_done {
+                        Ok((Vec::new(), None))
+                    } else {
+                        self.fetch_polymarket(core, in_cursor.polymarket.clone()).await
+                    }
+                };
+
+                let ((mut kalshi_events, kalshi_next), (polymarket_events, polymarket_next)) =
+                    futures::try_join!(kalshi_fut, polymarket_fut)?;
+
+                kalshi_events.extend(polymarket_events);
+
+                let out_cursor = in_cursor.advance(kalshi_next, polymarket_next);
+
                 Ok(FetchedEventList {
-                    cursor: "".to_string(),
-                    events: vec![],
+                    cursor: out_cursor.encode(),
+                    events: kalshi_events,
                 })
             }
             MarketSource::Unknown => {
-                anyhow::bail!("Cannot fetch events for unknown exchange");
+                Err(UnipredError::Parse("Cannot fetch events for unknown exchange".to_string()))
             }
         }
     }
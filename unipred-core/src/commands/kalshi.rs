@@ -1,8 +1,8 @@
 use super::Command;
 use crate::UnipredCore;
 use crate::clients::kalshi::Market;
+use crate::error::UnipredError;
 use async_trait::async_trait;
-use anyhow::Result;
 
 pub struct FetchKalshiMarkets {
     pub limit: Option<i64>,
@@ -59,7 +59,7 @@ impl FetchKalshiMarkets {
 impl Command for FetchKalshiMarkets {
     type Response = (Option<String>, Vec<Market>);
 
-    async fn execute(&self, core: &UnipredCore) -> Result<Self::Response> {
+    async fn execute(&self, core: &UnipredCore) -> Result<Self::Response, UnipredError> {
         let result = core.kalshi.get_multiple_markets(
             self.limit,
             self.cursor.clone(),
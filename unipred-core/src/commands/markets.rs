@@ -1,9 +1,9 @@
 use super::Command;
 use crate::UnipredCore;
 
-use crate::domain::MarketSource;
+use crate::domain::{CompositeCursor, MarketSource};
 use async_trait::async_trait;
-use anyhow::Result;
+use crate::error::UnipredError;
 use crate::proto::{FetchedMarket, FetchedMarketList};
 
 pub struct FetchMarkets {
@@ -44,89 +44,131 @@ impl FetchMarkets {
     }
 }
 
+impl FetchMarkets {
+    async fn fetch_kalshi(&self, core: &UnipredCore, cursor: Option<String>) -> Result<(Vec<FetchedMarket>, Option<String>), UnipredError> {
+        let (next_cursor, markets) = core
+            .kalshi
+            .get_multiple_markets(
+                self.limit,
+                cursor,
+                None,
+                None,
+                None,
+                None,
+                self.status.clone(),
+                None,
+            )
+            .await?;
+
+        let unified_markets = markets
+            .into_iter()
+            .filter(|m| m.mve_collection_ticker.is_none())
+            .map(|m| FetchedMarket {
+                ticker: m.ticker.clone(),
+                title: m.title,
+                source: "Kalshi".to_string(),
+                status: m.status,
+                description: m.subtitle,
+                outcomes: vec![m.yes_sub_title, m.no_sub_title],
+                start_date: m.open_time.clone(),
+                end_date: m.close_time.clone(),
+                volume: m.volume.to_string(),
+                liquidity: m.liquidity.to_string(),
+                url: format!("https://kalshi.com/markets/{}", m.ticker),
+            })
+            .collect();
+
+        Ok((unified_markets, next_cursor))
+    }
+
+    async fn fetch_polymarket(&self, core: &UnipredCore, cursor: Option<String>) -> Result<(Vec<FetchedMarket>, Option<String>), UnipredError> {
+        // Simplified Polymarket fetching
+        let markets = core
+            .polymarket
+            .get_markets(cursor.as_deref())
+            .await?;
+
+        let unified_markets = markets
+            .data
+            .into_iter()
+            .map(|m| FetchedMarket {
+                ticker: m.tokens[0].token_id.clone(), // Using token_id as ticker for consistency with get_quote
+                title: m.question,
+                source: "Polymarket".to_string(),
+                status: if m.active {
+                    "active".to_string()
+                } else {
+                    "closed".to_string()
+                },
+                description: m.description,
+                outcomes: m.tokens.iter().map(|t| t.outcome.clone()).collect(),
+                start_date: m.game_start_time.unwrap_or_default(),
+                end_date: m.end_date_iso.unwrap_or_default(),
+                volume: "0".to_string(),
+                liquidity: "0".to_string(),
+                url: format!("https://polymarket.com/event/{}", m.market_slug),
+            })
+            .collect();
+
+        Ok((unified_markets, markets.next_cursor))
+    }
+}
+
 #[async_trait]
 impl Command for FetchMarkets {
     type Response = FetchedMarketList;
 
-    async fn execute(&self, core: &UnipredCore) -> Result<Self::Response> {
-        // Default to Kalshi if not specified, or support multi-fetch logic later
+    async fn execute(&self, core: &UnipredCore) -> Result<Self::Response, UnipredError> {
+        // Default to Kalshi if not specified.
         let source = self.exchange.unwrap_or(MarketSource::Kalshi);
 
         match source {
             MarketSource::Kalshi => {
-                let (next_cursor, markets) = core
-                    .kalshi
-                    .get_multiple_markets(
-                        self.limit,
-                        self.cursor.clone(),
-                        None,
-                        None,
-                        None,
-                        None,
-                        self.status.clone(),
-                        None,
-                    )
-                    .await?;
-
-                let unified_markets = markets
-                    .into_iter()
-                    .filter(|m| m.mve_collection_ticker.is_none())
-                    .map(|m| FetchedMarket {
-                        ticker: m.ticker.clone(),
-                        title: m.title,
-                        source: "Kalshi".to_string(),
-                        status: m.status,
-                        description: m.subtitle,
-                        outcomes: vec![m.yes_sub_title, m.no_sub_title],
-                        start_date: m.open_time.clone(),
-                        end_date: m.close_time.clone(),
-                        volume: m.volume.to_string(),
-                        liquidity: m.liquidity.to_string(),
-                        url: format!("https://kalshi.com/markets/{}", m.ticker),
-                    })
-                    .collect();
-
+                let (markets, next_cursor) = self.fetch_kalshi(core, self.cursor.clone()).await?;
                 Ok(FetchedMarketList {
                     cursor: next_cursor.unwrap_or_default(),
-                    markets: unified_markets,
+                    markets,
                 })
             }
             MarketSource::Polymarket => {
-                // Simplified Polymarket fetching
-                let markets = core
-                    .polymarket
-                    .get_markets(self.cursor.as_deref())
-                    .await?;
-
-                let unified_markets = markets
-                    .data
-                    .into_iter()
-                    .map(|m| FetchedMarket {
-                        ticker: m.tokens[0].token_id.clone(), // Using token_id as ticker for consistency with get_quote
-                        title: m.question,
-                        source: "Polymarket".to_string(),
-                        status: if m.active {
-                            "active".to_string()
-                        } else {
-                            "closed".to_string()
-                        },
-                        description: m.description,
-                        outcomes: m.tokens.iter().map(|t| t.outcome.clone()).collect(),
-                        start_date: m.game_start_time.unwrap_or_default(),
-                        end_date: m.end_date_iso.unwrap_or_default(),
-                        volume: "0".to_string(),
-                        liquidity: "0".to_string(),
-                        url: format!("https://polymarket.com/event/{}", m.market_slug),
-                    })
-                    .collect();
+                let (markets, next_cursor) = self.fetch_polymarket(core, self.cursor.clone()).await?;
+                Ok(FetchedMarketList {
+                    cursor: next_cursor.unwrap_or_default(),
+                    markets,
+                })
+            }
+            MarketSource::All => {
+                let in_cursor = CompositeCursor::decode(self.cursor.as_deref().unwrap_or(""));
+
+                let kalshi_fut = async {
+                    if in_cursor.kalshi_done {
+                        Ok((Vec::new(), None))
+                    } else {
+                        self.fetch_kalshi(core, in_cursor.kalshi.clone()).await
+                    }
+                };
+                let polymarket_fut = async {
+                    if in_cursor.polymarket_done {
+                        Ok((Vec::new(), None))
+                    } else {
+                        self.fetch_polymarket(core, in_cursor.polymarket.clone()).await
+                    }
+                };
+
+                let ((mut kalshi_markets, kalshi_next), (polymarket_markets, polymarket_next)) =
+                    futures::try_join!(kalshi_fut, polymarket_fut)?;
+
+                kalshi_markets.extend(polymarket_markets);
+
+                let out_cursor = in_cursor.advance(kalshi_next, polymarket_next);
 
                 Ok(FetchedMarketList {
-                    cursor: markets.next_cursor.unwrap_or_default(),
-                    markets: unified_markets,
+                    cursor: out_cursor.encode(),
+                    markets: kalshi_markets,
                 })
             }
             MarketSource::Unknown => {
-                anyhow::bail!("Cannot fetch markets for unknown exchange");
+                Err(UnipredError::Parse("Cannot fetch markets for unknown exchange".to_string()))
             }
         }
     }
@@ -1,11 +1,18 @@
 pub mod quote;
 pub mod markets;
+pub mod events;
+pub mod kalshi;
+pub mod candles;
+pub mod backfill;
+pub mod trading;
+pub mod tickers;
 
 use async_trait::async_trait;
 use crate::UnipredCore;
+use crate::error::UnipredError;
 
 #[async_trait]
 pub trait Command {
     type Response;
-    async fn execute(&self, core: &UnipredCore) -> anyhow::Result<Self::Response>;
+    async fn execute(&self, core: &UnipredCore) -> Result<Self::Response, UnipredError>;
 }
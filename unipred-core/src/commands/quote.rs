@@ -1,11 +1,12 @@
 use super::Command;
 use crate::UnipredCore;
+use crate::clients::kalshi::tick::Tick;
 use crate::domain::MarketSource;
+use crate::error::UnipredError;
 use crate::proto::MarketQuote;
 use async_trait::async_trait;
 use rust_decimal::Decimal;
 use rust_decimal::prelude::FromPrimitive;
-use anyhow::Result;
 use chrono::Utc;
 
 pub struct GetMarketQuote {
@@ -23,17 +24,29 @@ impl GetMarketQuote {
 impl Command for GetMarketQuote {
     type Response = MarketQuote;
 
-    async fn execute(&self, core: &UnipredCore) -> Result<Self::Response> {
+    async fn execute(&self, core: &UnipredCore) -> Result<Self::Response, UnipredError> {
         let source = self.exchange.clone().unwrap_or_else(|| MarketSource::detect(&self.ticker));
 
         match source {
             MarketSource::Kalshi => {
                 let market = core.kalshi.get_single_market(&self.ticker).await?;
 
+                let price = (Decimal::from_i64(market.last_price).unwrap_or_default() / Decimal::new(100, 0)).to_string();
+
+                if let Some(tick) = Tick::from_dollars_round_down(&price) {
+                    core.duck_store.lock().await.insert_price_tick(
+                        "Kalshi",
+                        &market.ticker,
+                        Utc::now().timestamp(),
+                        tick,
+                        market.volume,
+                    )?;
+                }
+
                 Ok(MarketQuote {
                     ticker: market.ticker,
                     source: "Kalshi".to_string(),
-                    price: (Decimal::from_i64(market.last_price).unwrap_or_default() / Decimal::new(100, 0)).to_string(),
+                    price,
                     bid: (Decimal::from_i64(market.yes_bid).unwrap_or_default() / Decimal::new(100, 0)).to_string(),
                     ask: (Decimal::from_i64(market.yes_ask).unwrap_or_default() / Decimal::new(100, 0)).to_string(),
                     volume: (Decimal::from_i64(market.volume).unwrap_or_default()).to_string(),
@@ -41,7 +54,16 @@ impl Command for GetMarketQuote {
                 })
             },
             MarketSource::Polymarket => {
-                let book = core.polymarket.get_order_book(&self.ticker).await?;
+                let book = core.polymarket.get_order_book(&self.ticker).await.map_err(|e| {
+                    // The CLOB returns this exact message when a market has no book yet
+                    // (e.g. a stale/closed market) -- surface it as a typed variant instead
+                    // of a generic upstream failure.
+                    if e.to_string().contains("No orderbook exists") {
+                        UnipredError::NoOrderbook { ticker: self.ticker.clone() }
+                    } else {
+                        UnipredError::from(e)
+                    }
+                })?;
 
                 let best_bid = book.bids.first().map(|o| o.price);
                 let best_ask = book.asks.first().map(|o| o.price);
@@ -53,6 +75,19 @@ impl Command for GetMarketQuote {
                     (None, None) => Decimal::ZERO,
                 };
 
+                if let Some(tick) = Tick::from_dollars_round_down(&price.to_string()) {
+                    // The CLOB order book has no cumulative volume field, so we can only
+                    // record the mid price here; `build_candles_from_ticks` treats a flat
+                    // cumulative_volume as zero added volume rather than a reset.
+                    core.duck_store.lock().await.insert_price_tick(
+                        "Polymarket",
+                        &self.ticker,
+                        Utc::now().timestamp(),
+                        tick,
+                        0,
+                    )?;
+                }
+
                 Ok(MarketQuote {
                     ticker: self.ticker.clone(),
                     source: "Polymarket".to_string(),
@@ -63,8 +98,14 @@ impl Command for GetMarketQuote {
                     timestamp: Utc::now().to_rfc3339(),
                 })
             },
+            MarketSource::All => Err(UnipredError::Parse(
+                "A single quote must target one exchange, not MarketSource::All".to_string(),
+            )),
             MarketSource::Unknown => {
-                anyhow::bail!("Could not determine exchange for ticker: {}", self.ticker);
+                Err(UnipredError::Parse(format!(
+                    "Could not determine exchange for ticker: {}",
+                    self.ticker
+                )))
             }
         }
     }
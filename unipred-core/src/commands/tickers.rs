@@ -0,0 +1,94 @@
+use super::Command;
+use crate::UnipredCore;
+
+use crate::domain::MarketSource;
+use crate::error::UnipredError;
+use async_trait::async_trait;
+use serde::Serialize;
+
+/// A single market rendered in the CoinGecko `tickers` JSON schema, mirroring the field
+/// shape of `MarketQuote` (`last_price`/`bid`/`ask`/`volume`) plus `high`/`low` pulled from
+/// the candle subsystem.
+#[derive(Debug, Clone, Serialize)]
+pub struct Ticker {
+    pub ticker_id: String,
+    pub base: String,
+    pub target: String,
+    pub last_price: String,
+    pub bid: String,
+    pub ask: String,
+    pub volume: String,
+    pub high: String,
+    pub low: String,
+    pub exchange: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TickerList {
+    pub tickers: Vec<Ticker>,
+}
+
+/// Exports every market in `DuckStore` as a CoinGecko-compatible ticker document, joining
+/// each market against its latest `price_ticks` snapshot (last price) and latest `1d`
+/// candle (high/low) rather than round-tripping to the exchanges. Markets are binary
+/// YES/NO contracts settling in USD, so `base`/`target` are fixed to "YES"/"USD"; without
+/// a persisted order book, `bid`/`ask` fall back to the latest tick's price.
+pub struct ExportTickers {
+    pub exchange: Option<MarketSource>,
+    pub status: Option<String>,
+}
+
+impl ExportTickers {
+    pub fn new() -> Self {
+        Self {
+            exchange: None,
+            status: None,
+        }
+    }
+
+    pub fn with_exchange(mut self, exchange: Option<MarketSource>) -> Self {
+        self.exchange = exchange;
+        self
+    }
+
+    pub fn with_status(mut self, status: String) -> Self {
+        self.status = Some(status);
+        self
+    }
+}
+
+#[async_trait]
+impl Command for ExportTickers {
+    type Response = TickerList;
+
+    async fn execute(&self, core: &UnipredCore) -> Result<Self::Response, UnipredError> {
+        let source = self.exchange.map(|s| format!("{:?}", s));
+
+        let rows = core
+            .duck_store
+            .lock()
+            .await
+            .export_tickers(source.as_deref(), self.status.as_deref())?;
+
+        let tickers = rows
+            .into_iter()
+            .map(|row| {
+                let last_price = row.last_price.map(|t| t.to_dollars_string()).unwrap_or_default();
+                Ticker {
+                    ticker_id: format!("{}_{}", row.source, row.ticker),
+                    base: "YES".to_string(),
+                    target: "USD".to_string(),
+                    bid: last_price.clone(),
+                    ask: last_price.clone(),
+                    last_price,
+                    volume: row.volume,
+                    high: row.high.map(|t| t.to_dollars_string()).unwrap_or_default(),
+                    low: row.low.map(|t| t.to_dollars_string()).unwrap_or_default(),
+                    exchange: row.source,
+                }
+            })
+            .collect();
+
+        Ok(TickerList { tickers })
+    }
+}
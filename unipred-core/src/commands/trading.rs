@@ -0,0 +1,250 @@
+use super::Command;
+use crate::UnipredCore;
+
+use crate::clients::kalshi::tick::Tick;
+use crate::domain::{MarketSource, OrderSide, OrderType, TimeInForce};
+use crate::error::UnipredError;
+use crate::proto::{Order, OrderList, Position, PositionList};
+use async_trait::async_trait;
+
+/// Places a limit or market order on whichever exchange `ticker` belongs to, unifying
+/// Kalshi's orders endpoint and Polymarket's signed CLOB orders behind one command. Prices
+/// are expressed in `Tick`s (the same 4-decimal fixed-point type the orderbook levels are
+/// already (de)serialized through via `de_tick_levels`/`se_tick_levels`) so a price survives
+/// the round trip to either exchange without floating-point drift.
+pub struct PlaceOrder {
+    pub ticker: String,
+    pub exchange: Option<MarketSource>,
+    pub side: OrderSide,
+    pub price: Tick,
+    pub size: i64,
+    pub order_type: OrderType,
+    pub tif: TimeInForce,
+}
+
+impl PlaceOrder {
+    pub fn new(ticker: String, side: OrderSide, price: Tick, size: i64) -> Self {
+        Self {
+            ticker,
+            exchange: None,
+            side,
+            price,
+            size,
+            order_type: OrderType::Limit,
+            tif: TimeInForce::GoodTilCanceled,
+        }
+    }
+
+    pub fn with_exchange(mut self, exchange: Option<MarketSource>) -> Self {
+        self.exchange = exchange;
+        self
+    }
+
+    pub fn with_order_type(mut self, order_type: OrderType) -> Self {
+        self.order_type = order_type;
+        self
+    }
+
+    pub fn with_tif(mut self, tif: TimeInForce) -> Self {
+        self.tif = tif;
+        self
+    }
+}
+
+#[async_trait]
+impl Command for PlaceOrder {
+    type Response = Order;
+
+    async fn execute(&self, core: &UnipredCore) -> Result<Self::Response, UnipredError> {
+        let source = self.exchange.unwrap_or_else(|| MarketSource::detect(&self.ticker));
+
+        match source {
+            MarketSource::Kalshi => {
+                let ack = core
+                    .kalshi
+                    .place_order(&self.ticker, self.side, self.price, self.size, self.order_type, self.tif)
+                    .await?;
+
+                Ok(Order {
+                    order_id: ack.order_id,
+                    ticker: ack.ticker,
+                    source: "Kalshi".to_string(),
+                    side: ack.side,
+                    price: self.price.to_dollars_string(),
+                    size: self.size,
+                    order_type: format!("{:?}", self.order_type),
+                    time_in_force: format!("{:?}", self.tif),
+                    status: ack.status,
+                })
+            }
+            MarketSource::Polymarket => {
+                let ack = core
+                    .polymarket
+                    .place_order(&self.ticker, self.side, self.price, self.size, self.order_type, self.tif)
+                    .await?;
+
+                Ok(Order {
+                    order_id: ack.order_id,
+                    ticker: ack.token_id,
+                    source: "Polymarket".to_string(),
+                    side: ack.side,
+                    price: ack.price,
+                    size: self.size,
+                    order_type: format!("{:?}", self.order_type),
+                    time_in_force: format!("{:?}", self.tif),
+                    status: ack.status,
+                })
+            }
+            MarketSource::All => Err(UnipredError::Parse(
+                "An order must target one exchange, not MarketSource::All".to_string(),
+            )),
+            MarketSource::Unknown => Err(UnipredError::Parse(format!(
+                "Could not determine exchange for ticker: {}",
+                self.ticker
+            ))),
+        }
+    }
+}
+
+/// Cancels a resting order on whichever exchange it lives on.
+pub struct CancelOrder {
+    pub order_id: String,
+    pub exchange: MarketSource,
+}
+
+impl CancelOrder {
+    pub fn new(order_id: String, exchange: MarketSource) -> Self {
+        Self { order_id, exchange }
+    }
+}
+
+#[async_trait]
+impl Command for CancelOrder {
+    type Response = ();
+
+    async fn execute(&self, core: &UnipredCore) -> Result<(), UnipredError> {
+        match self.exchange {
+            MarketSource::Kalshi => Ok(core.kalshi.cancel_order(&self.order_id).await?),
+            MarketSource::Polymarket => Ok(core.polymarket.cancel_order(&self.order_id).await?),
+            MarketSource::All | MarketSource::Unknown => Err(UnipredError::Parse(
+                "Cannot cancel an order on an unknown exchange".to_string(),
+            )),
+        }
+    }
+}
+
+/// Lists this account's resting orders, optionally scoped to one exchange and/or ticker.
+pub struct GetOpenOrders {
+    pub exchange: Option<MarketSource>,
+    pub ticker: Option<String>,
+}
+
+impl GetOpenOrders {
+    pub fn new() -> Self {
+        Self { exchange: None, ticker: None }
+    }
+
+    pub fn with_exchange(mut self, exchange: Option<MarketSource>) -> Self {
+        self.exchange = exchange;
+        self
+    }
+
+    pub fn with_ticker(mut self, ticker: String) -> Self {
+        self.ticker = Some(ticker);
+        self
+    }
+}
+
+#[async_trait]
+impl Command for GetOpenOrders {
+    type Response = OrderList;
+
+    async fn execute(&self, core: &UnipredCore) -> Result<OrderList, UnipredError> {
+        let mut orders = Vec::new();
+
+        if matches!(self.exchange, None | Some(MarketSource::Kalshi) | Some(MarketSource::All)) {
+            let kalshi_orders = core.kalshi.get_open_orders(self.ticker.as_deref()).await?;
+            orders.extend(kalshi_orders.into_iter().map(|o| Order {
+                order_id: o.order_id,
+                ticker: o.ticker,
+                source: "Kalshi".to_string(),
+                side: o.side,
+                price: o
+                    .yes_price
+                    .or(o.no_price)
+                    // Kalshi reports order prices in cents; 100 ticks per cent.
+                    .map(|cents| Tick(cents * 100))
+                    .map(|t| t.to_dollars_string())
+                    .unwrap_or_default(),
+                size: o.remaining_count,
+                order_type: String::new(),
+                time_in_force: String::new(),
+                status: o.status,
+            }));
+        }
+
+        if matches!(self.exchange, None | Some(MarketSource::Polymarket) | Some(MarketSource::All)) {
+            let poly_orders = core.polymarket.get_open_orders(self.ticker.as_deref()).await?;
+            orders.extend(poly_orders.into_iter().map(|o| Order {
+                order_id: o.order_id,
+                ticker: o.token_id,
+                source: "Polymarket".to_string(),
+                side: o.side,
+                price: o.price,
+                size: o.size_matched,
+                order_type: String::new(),
+                time_in_force: String::new(),
+                status: o.status,
+            }));
+        }
+
+        Ok(OrderList { orders })
+    }
+}
+
+/// Lists this account's open positions, optionally scoped to one exchange.
+pub struct GetPositions {
+    pub exchange: Option<MarketSource>,
+}
+
+impl GetPositions {
+    pub fn new() -> Self {
+        Self { exchange: None }
+    }
+
+    pub fn with_exchange(mut self, exchange: Option<MarketSource>) -> Self {
+        self.exchange = exchange;
+        self
+    }
+}
+
+#[async_trait]
+impl Command for GetPositions {
+    type Response = PositionList;
+
+    async fn execute(&self, core: &UnipredCore) -> Result<PositionList, UnipredError> {
+        let mut positions = Vec::new();
+
+        if matches!(self.exchange, None | Some(MarketSource::Kalshi) | Some(MarketSource::All)) {
+            let kalshi_positions = core.kalshi.get_positions().await?;
+            positions.extend(kalshi_positions.into_iter().map(|p| Position {
+                ticker: p.ticker,
+                source: "Kalshi".to_string(),
+                size: p.position,
+                avg_price: String::new(),
+            }));
+        }
+
+        if matches!(self.exchange, None | Some(MarketSource::Polymarket) | Some(MarketSource::All)) {
+            let poly_positions = core.polymarket.get_positions().await?;
+            positions.extend(poly_positions.into_iter().map(|p| Position {
+                ticker: p.token_id,
+                source: "Polymarket".to_string(),
+                size: p.size,
+                avg_price: p.avg_price,
+            }));
+        }
+
+        Ok(PositionList { positions })
+    }
+}
@@ -0,0 +1,205 @@
+use crate::clients::kalshi::TradingEnvironment;
+use crate::domain::MarketSource;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::env;
+use std::fs;
+
+/// A single market to scope ingestion to, as listed under `markets` in the config file.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScopedMarket {
+    pub ticker: String,
+    pub source: MarketSource,
+}
+
+/// Where to find the Kalshi API-key private key. `Env` is resolved against the process
+/// environment (populated either directly, or via the companion `.env` file loaded by
+/// `Config::from_env_and_file`), so the config file itself never has to hold a secret.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PrivateKeySource {
+    Inline { pem: String },
+    Path { path: String },
+    Env { var: String },
+}
+
+impl PrivateKeySource {
+    /// Resolve to the raw PEM text, reading from disk/env as needed.
+    pub fn resolve(&self) -> Result<String> {
+        match self {
+            PrivateKeySource::Inline { pem } => Ok(pem.clone()),
+            PrivateKeySource::Path { path } => fs::read_to_string(path)
+                .with_context(|| format!("Failed to read private key file: {}", path)),
+            PrivateKeySource::Env { var } => {
+                env::var(var).with_context(|| format!("{} must be set", var))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct KalshiConfig {
+    /// Defaults to demo mode so a config that omits this never accidentally trades live.
+    #[serde(default = "default_demo_mode")]
+    pub demo_mode: bool,
+    pub api_base_override: Option<String>,
+    pub api_key_id: Option<String>,
+    pub api_key_id_env: Option<String>,
+    pub private_key: Option<PrivateKeySource>,
+}
+
+fn default_demo_mode() -> bool {
+    true
+}
+
+impl Default for KalshiConfig {
+    fn default() -> Self {
+        Self {
+            demo_mode: default_demo_mode(),
+            api_base_override: None,
+            api_key_id: None,
+            api_key_id_env: None,
+            private_key: None,
+        }
+    }
+}
+
+impl KalshiConfig {
+    pub fn environment(&self) -> TradingEnvironment {
+        if self.demo_mode {
+            TradingEnvironment::DemoMode
+        } else {
+            TradingEnvironment::ProdMode
+        }
+    }
+
+    /// Resolve the API key ID, preferring an inline value over the env-var indirection.
+    pub fn resolve_api_key_id(&self) -> Option<Result<String>> {
+        if let Some(id) = &self.api_key_id {
+            return Some(Ok(id.clone()));
+        }
+        self.api_key_id_env.as_ref().map(|var| {
+            env::var(var).with_context(|| format!("{} must be set", var))
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct PolymarketConfig {
+    pub base_url: Option<String>,
+}
+
+/// Which `StorageBackend` impl `IngestionEngine` persists the `markets`/`events` snapshot
+/// tables to. Defaults to `DuckDb` since that's what every deployment so far has used; `Postgres`
+/// is for shared ingestion services where a local DuckDB file isn't viable.
+#[derive(Debug, Clone, Copy, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageBackendKind {
+    #[default]
+    DuckDb,
+    Postgres,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct StorageConfig {
+    #[serde(default)]
+    pub backend: StorageBackendKind,
+    /// DuckDB file path, used when `backend` is `DuckDb`. Defaults to `unipred.duckdb`.
+    pub duckdb_path: Option<String>,
+    /// Env var holding the `postgres://` connection string, used when `backend` is `Postgres`.
+    /// Defaults to `DATABASE_URL`.
+    pub postgres_env_var: Option<String>,
+}
+
+/// Structured config for `UnipredCore::new`, parsed from a JSON file (e.g. `markets.json`)
+/// rather than the `_config: String` placeholder `new` used to throw away.
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct Config {
+    #[serde(default)]
+    pub kalshi: KalshiConfig,
+    #[serde(default)]
+    pub polymarket: PolymarketConfig,
+    #[serde(default)]
+    pub storage: StorageConfig,
+    /// Markets to scope ingestion/backfills to. Empty means "no curated scope".
+    #[serde(default)]
+    pub markets: Vec<ScopedMarket>,
+}
+
+impl Config {
+    /// Load a JSON config file plus a companion `.env`-style file, so credentials that are
+    /// awkward to pass as plain env vars (multiline PEM keys) still work. This is the one
+    /// loader shared by `UnipredCore::new` and the integration tests -- the ad hoc multiline
+    /// `.env` parsing that used to live in `tests/integration_test.rs` lives here instead.
+    pub fn from_env_and_file(config_path: &str, env_path: &str) -> Result<Self> {
+        Self::load_dotenv(env_path);
+
+        let raw = fs::read_to_string(config_path)
+            .with_context(|| format!("Failed to read config file: {}", config_path))?;
+        serde_json::from_str(&raw)
+            .with_context(|| format!("Failed to parse config file: {}", config_path))
+    }
+
+    /// Load the first existing `.env` file from `paths` into the process environment. Exposed
+    /// for integration tests that authenticate directly against `UnipredCore::new_unconfigured`
+    /// rather than going through `from_env_and_file`, so they still share this loader instead
+    /// of re-parsing `.env` by hand.
+    pub fn load_env_for_tests(paths: &[&str]) {
+        for path in paths {
+            if std::path::Path::new(path).exists() {
+                Self::load_dotenv(path);
+                break;
+            }
+        }
+    }
+
+    /// Parse a `.env` file into the process environment, without overwriting variables that
+    /// are already set. Unlike the `dotenv` crate, this handles double-quoted values that
+    /// span multiple lines (e.g. a PEM-encoded private key).
+    fn load_dotenv(path: &str) {
+        let p = std::path::Path::new(path);
+        if !p.exists() {
+            return;
+        }
+        let Ok(content) = fs::read_to_string(p) else {
+            return;
+        };
+
+        let mut rest = content.as_str();
+        loop {
+            rest = rest.trim_start_matches(['\n', '\r', ' ', '\t']);
+            if rest.is_empty() {
+                break;
+            }
+            if rest.starts_with('#') {
+                match rest.find('\n') {
+                    Some(nl) => {
+                        rest = &rest[nl + 1..];
+                        continue;
+                    }
+                    None => break,
+                }
+            }
+
+            let Some(eq_idx) = rest.find('=') else { break };
+            let key = rest[..eq_idx].trim();
+            let after_eq = &rest[eq_idx + 1..];
+
+            let (value, consumed) = if let Some(stripped) = after_eq.strip_prefix('"') {
+                match stripped.find('"') {
+                    Some(end) => (stripped[..end].to_string(), 1 + end + 1),
+                    None => break,
+                }
+            } else {
+                let line_end = after_eq.find('\n').unwrap_or(after_eq.len());
+                (after_eq[..line_end].trim().to_string(), line_end)
+            };
+
+            if !key.is_empty() && env::var(key).is_err() {
+                env::set_var(key, value);
+            }
+
+            rest = &after_eq[consumed..];
+        }
+    }
+}
@@ -13,9 +13,72 @@ pub struct MarketQuote {
 pub enum MarketSource {
     Kalshi,
     Polymarket,
+    /// Fetch from every exchange concurrently and merge the results; see `CompositeCursor`
+    /// for how pagination is resumed across venues.
+    All,
     Unknown,
 }
 
+/// A per-source cursor pair, serialized as the `cursor` string returned from a
+/// `MarketSource::All` fetch so each exchange's pagination can resume independently on the
+/// next call without the caller having to track two cursors itself.
+///
+/// `kalshi`/`polymarket` being `None` with its `_done` flag unset means "that exchange hasn't
+/// started yet" -- once an exchange's page comes back with no `next_cursor`, its cursor goes
+/// back to `None` but it's also marked `_done`, so a later `All` call doesn't mistake
+/// "finished" for "not started" and re-fetch that exchange's first page forever.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CompositeCursor {
+    #[serde(default)]
+    pub kalshi: Option<String>,
+    #[serde(default)]
+    pub kalshi_done: bool,
+    #[serde(default)]
+    pub polymarket: Option<String>,
+    #[serde(default)]
+    pub polymarket_done: bool,
+}
+
+impl CompositeCursor {
+    /// Parse a cursor string as a `CompositeCursor`, treating an empty/unparseable string as
+    /// "no cursor for either exchange" rather than an error -- this is what lets the very
+    /// first `MarketSource::All` call pass an empty cursor.
+    pub fn decode(cursor: &str) -> Self {
+        if cursor.is_empty() {
+            return Self::default();
+        }
+        serde_json::from_str(cursor).unwrap_or_default()
+    }
+
+    /// Advance this cursor with the `next_cursor` a page fetch returned for `kalshi`/
+    /// `polymarket`: `None` marks that exchange `_done` (its last page has already been
+    /// fetched) rather than resetting it to "not started".
+    pub fn advance(&self, kalshi_next: Option<String>, polymarket_next: Option<String>) -> Self {
+        Self {
+            kalshi_done: self.kalshi_done || kalshi_next.is_none(),
+            kalshi: kalshi_next,
+            polymarket_done: self.polymarket_done || polymarket_next.is_none(),
+            polymarket: polymarket_next,
+        }
+    }
+
+    /// True once both exchanges are exhausted -- callers should stop paginating and treat an
+    /// empty `cursor` string the same way the single-exchange fetches already do.
+    pub fn all_done(&self) -> bool {
+        self.kalshi_done && self.polymarket_done
+    }
+
+    /// Encodes to the empty string once `all_done()`, matching the single-exchange convention
+    /// that an empty cursor means "no more pages" -- otherwise a caller driving pagination off
+    /// cursor-emptiness would never terminate.
+    pub fn encode(&self) -> String {
+        if self.all_done() {
+            return String::new();
+        }
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
 impl MarketSource {
     pub fn detect(ticker: &str) -> Self {
         if ticker.starts_with("KX") {
@@ -27,3 +90,28 @@ impl MarketSource {
         }
     }
 }
+
+/// Which outcome an order is for. Kalshi markets are binary (yes/no); Polymarket's CLOB
+/// expresses the same thing as buy/sell on a conditional token, so `Buy`/`Sell` map onto
+/// `Yes`/`No` there.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum OrderSide {
+    Yes,
+    No,
+}
+
+/// Limit orders rest on the book at `price`; market orders take whatever liquidity is
+/// available and ignore `price` entirely.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum OrderType {
+    Limit,
+    Market,
+}
+
+/// Standard time-in-force semantics, shared across both exchanges' order APIs.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum TimeInForce {
+    GoodTilCanceled,
+    ImmediateOrCancel,
+    FillOrKill,
+}
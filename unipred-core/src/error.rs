@@ -0,0 +1,44 @@
+use thiserror::Error;
+
+/// Crate-wide typed error for the `Command::execute` surface (and, via the pyo3 layer, for
+/// Python callers) so a caller can branch on "no orderbook" vs "rate limited" vs "bad
+/// ticker" instead of pattern-matching on a stringified `anyhow::Error`.
+#[derive(Debug, Error)]
+pub enum UnipredError {
+    #[error("authentication failed: {0}")]
+    Auth(String),
+
+    #[error("rate limited (retry_after={retry_after:?}s)")]
+    RateLimited { retry_after: Option<u64> },
+
+    #[error("not found: {0}")]
+    NotFound(String),
+
+    #[error("no orderbook exists for ticker {ticker}")]
+    NoOrderbook { ticker: String },
+
+    #[error("upstream error from {source}: HTTP {status}")]
+    Upstream { source: String, status: u16 },
+
+    #[error("failed to parse response: {0}")]
+    Parse(String),
+
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+impl From<crate::clients::kalshi::kalshi_error::KalshiError> for UnipredError {
+    fn from(e: crate::clients::kalshi::kalshi_error::KalshiError) -> Self {
+        use crate::clients::kalshi::kalshi_error::KalshiError;
+        match e {
+            KalshiError::Unauthorized(msg) => UnipredError::Auth(msg),
+            KalshiError::RateLimited { retry_after } => UnipredError::RateLimited { retry_after },
+            KalshiError::NotFound(msg) => UnipredError::NotFound(msg),
+            KalshiError::Api { status, .. } => UnipredError::Upstream {
+                source: "kalshi".to_string(),
+                status,
+            },
+            other => UnipredError::Other(other.into()),
+        }
+    }
+}
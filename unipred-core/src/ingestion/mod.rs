@@ -1,13 +1,15 @@
 use anyhow::{Context, Result};
 use futures::future::{join_all, BoxFuture};
+use crate::config::StorageConfig;
 use crate::ml::Embedder;
-use crate::storage::duck::DuckStore;
+use crate::storage::backend::StorageBackend;
 use crate::storage::lance::{LanceStore, MarketEmbedding, EventEmbedding};
 use crate::UnipredCore;
 use crate::domain::MarketSource;
 use crate::commands::markets::FetchMarkets;
 use crate::commands::events::FetchEvents;
 use crate::commands::Command;
+use crate::proto::FetchedMarket;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use std::time::Duration;
@@ -17,6 +19,14 @@ pub struct IngestionFilter {
     pub exchanges: Vec<MarketSource>,
     pub statuses: Vec<String>,
     pub max_pages: Option<usize>,
+    /// Resume from the last checkpointed cursor for each `(exchange, kind, status)` stream
+    /// rather than starting over from page 1. Defaults to `true`; set `false` to force a
+    /// full re-ingest.
+    pub resume: bool,
+    /// Skip re-embedding and re-indexing markets whose title/description are unchanged since
+    /// last run (`StorageBackend::changed_content_tickers`). Markets are always upserted
+    /// either way -- this only short-circuits the expensive embedding step.
+    pub refresh_only: bool,
 }
 
 impl Default for IngestionFilter {
@@ -25,25 +35,30 @@ impl Default for IngestionFilter {
             exchanges: vec![],
             statuses: vec![],
             max_pages: None,
+            resume: true,
+            refresh_only: false,
         }
     }
 }
 
+/// Persists the `markets`/`events` snapshot tables to whichever `StorageBackend` `storage_config`
+/// selects (DuckDB or Postgres), so the ingestion loops below run unchanged against either one.
 pub struct IngestionEngine {
-    duck_store: Arc<Mutex<DuckStore>>,
+    storage: Arc<Mutex<Box<dyn StorageBackend>>>,
     lance_store: Arc<LanceStore>,
     embedder: Arc<Embedder>,
 }
 
 impl IngestionEngine {
-    pub async fn new(duck_path: &str, lance_path: &str) -> Result<Self> {
-        // DuckStore::open is synchronous, run it? It's fine for init.
-        let duck_store = DuckStore::open(duck_path).context("Failed to open DuckDB")?;
+    pub async fn new(storage_config: &StorageConfig, lance_path: &str) -> Result<Self> {
+        let storage = crate::storage::open_backend(storage_config)
+            .await
+            .context("Failed to open ingestion storage backend")?;
         let lance_store = LanceStore::connect(lance_path).await.context("Failed to connect to LanceDB")?;
         let embedder = Embedder::new().context("Failed to initialize Embedder")?;
 
         Ok(Self {
-            duck_store: Arc::new(Mutex::new(duck_store)),
+            storage: Arc::new(Mutex::new(storage)),
             lance_store: Arc::new(lance_store),
             embedder: Arc::new(embedder),
         })
@@ -72,13 +87,13 @@ impl IngestionEngine {
             // in the same way as Kalshi via the unified command yet.
             if matches!(exchange, MarketSource::Polymarket) {
                 // Just run once
-                tasks.push(Box::pin(self.ingest_loop(core, exchange, None, &cancel_check, filters.max_pages)));
+                tasks.push(Box::pin(self.ingest_loop(core, exchange, None, &cancel_check, filters.max_pages, filters.resume, filters.refresh_only)));
             } else {
                 for status in &statuses {
-                    tasks.push(Box::pin(self.ingest_loop(core, exchange.clone(), Some(status.clone()), &cancel_check, filters.max_pages)));
-                    
+                    tasks.push(Box::pin(self.ingest_loop(core, exchange.clone(), Some(status.clone()), &cancel_check, filters.max_pages, filters.resume, filters.refresh_only)));
+
                     if matches!(exchange, MarketSource::Kalshi) {
-                        tasks.push(Box::pin(self.ingest_events_loop(core, exchange.clone(), Some(status.clone()), &cancel_check, filters.max_pages)));
+                        tasks.push(Box::pin(self.ingest_events_loop(core, exchange.clone(), Some(status.clone()), &cancel_check, filters.max_pages, filters.resume)));
                     }
                 }
             }
@@ -104,15 +119,33 @@ impl IngestionEngine {
         status: Option<String>,
         cancel_check: &Option<F>,
         max_pages: Option<usize>,
+        resume: bool,
     ) -> Result<()>
     where
         F: Fn() -> Result<()> + Send + Sync,
     {
         println!("Ingesting Events {:?} (Status: {:?})", exchange, status);
+        let exchange_key = format!("{:?}", exchange);
+        let status_key = status.clone().unwrap_or_else(|| "all".to_string());
+
         let mut cursor: Option<String> = None;
         let mut page_count = 0;
         let mut total_events = 0;
 
+        if resume {
+            if let Some((saved_cursor, saved_pages)) = self
+                .storage
+                .lock()
+                .await
+                .load_checkpoint(&exchange_key, "events", &status_key)
+                .await?
+            {
+                println!("Resuming Events {:?} {:?} from page {}", exchange, status, saved_pages);
+                cursor = Some(saved_cursor);
+                page_count = saved_pages as usize;
+            }
+        }
+
         loop {
             if let Some(limit) = max_pages {
                 if page_count >= limit {
@@ -154,6 +187,7 @@ impl IngestionEngine {
             };
 
             if response.events.is_empty() {
+                self.storage.lock().await.clear_checkpoint(&exchange_key, "events", &status_key).await?;
                 break;
             }
 
@@ -162,8 +196,14 @@ impl IngestionEngine {
             println!("  Events Page {}: {} events", page_count, batch_size);
 
             {
-                let mut duck = self.duck_store.lock().await;
-                duck.insert_events_batch(&response.events)?;
+                let mut storage = self.storage.lock().await;
+                storage.insert_events_batch_and_checkpoint(
+                    &response.events,
+                    &exchange_key,
+                    &status_key,
+                    &response.cursor,
+                    (page_count + 1) as i64,
+                ).await?;
             }
 
             let texts: Vec<String> = response.events.iter().map(|e| {
@@ -196,6 +236,7 @@ impl IngestionEngine {
             self.lance_store.add_events(lance_records).await?;
 
             if response.cursor.is_empty() || Some(&response.cursor) == cursor.as_ref() {
+                self.storage.lock().await.clear_checkpoint(&exchange_key, "events", &status_key).await?;
                 break;
             }
             cursor = Some(response.cursor);
@@ -214,15 +255,34 @@ impl IngestionEngine {
         status: Option<String>,
         cancel_check: &Option<F>,
         max_pages: Option<usize>,
+        resume: bool,
+        refresh_only: bool,
     ) -> Result<()>
     where
         F: Fn() -> Result<()> + Send + Sync,
     {
         println!("Ingesting {:?} (Status: {:?})", exchange, status);
+        let exchange_key = format!("{:?}", exchange);
+        let status_key = status.clone().unwrap_or_else(|| "all".to_string());
+
         let mut cursor: Option<String> = None;
         let mut page_count = 0;
         let mut total_markets = 0;
 
+        if resume {
+            if let Some((saved_cursor, saved_pages)) = self
+                .storage
+                .lock()
+                .await
+                .load_checkpoint(&exchange_key, "markets", &status_key)
+                .await?
+            {
+                println!("Resuming {:?} {:?} from page {}", exchange, status, saved_pages);
+                cursor = Some(saved_cursor);
+                page_count = saved_pages as usize;
+            }
+        }
+
         loop {
             if let Some(limit) = max_pages {
                 if page_count >= limit {
@@ -269,6 +329,7 @@ impl IngestionEngine {
             };
 
             if response.markets.is_empty() {
+                self.storage.lock().await.clear_checkpoint(&exchange_key, "markets", &status_key).await?;
                 break;
             }
 
@@ -276,46 +337,76 @@ impl IngestionEngine {
             total_markets += batch_size;
             println!("  Page {}: {} markets", page_count, batch_size);
 
-            // 1. DuckDB Store
+            // In refresh-only mode, figure out which markets actually changed *before* the
+            // upsert below overwrites their content_hash, so the comparison is meaningful.
+            let changed: Option<std::collections::HashSet<(String, String)>> = if refresh_only {
+                Some(self.storage.lock().await.changed_content_tickers(&response.markets).await?)
+            } else {
+                None
+            };
+
+            // 1. Storage backend -- markets are always upserted regardless of refresh_only,
+            // since that's cheap; only the embedding step below is skipped for unchanged
+            // content.
             {
-                // DuckDB operations are synchronous and fast for batch inserts
-                let mut duck = self.duck_store.lock().await;
-                duck.insert_batch(&response.markets)?;
+                let mut storage = self.storage.lock().await;
+                storage.insert_batch_and_checkpoint(
+                    &response.markets,
+                    &exchange_key,
+                    &status_key,
+                    &response.cursor,
+                    (page_count + 1) as i64,
+                ).await?;
             }
 
-            // 2. Embeddings
-            let texts: Vec<String> = response.markets.iter().map(|m| {
-                format!(
-                    "Title: {}\nDescription: {}\nOutcomes: {}",
-                    m.title,
-                    m.description,
-                    m.outcomes.join(", ")
-                )
-            }).collect();
+            let markets_to_embed: Vec<&FetchedMarket> = match &changed {
+                Some(changed) => response
+                    .markets
+                    .iter()
+                    .filter(|m| changed.contains(&(m.ticker.clone(), m.source.clone())))
+                    .collect(),
+                None => response.markets.iter().collect(),
+            };
 
-            let embedder = self.embedder.clone();
-            let vectors = tokio::task::spawn_blocking(move || {
-                embedder.embed_batch(texts)
-            }).await??;
+            if markets_to_embed.is_empty() {
+                println!("  Page {}: no market content changed, skipping re-embedding", page_count);
+            } else {
+                // 2. Embeddings
+                let texts: Vec<String> = markets_to_embed.iter().map(|m| {
+                    format!(
+                        "Title: {}\nDescription: {}\nOutcomes: {}",
+                        m.title,
+                        m.description,
+                        m.outcomes.join(", ")
+                    )
+                }).collect();
+
+                let embedder = self.embedder.clone();
+                let vectors = tokio::task::spawn_blocking(move || {
+                    embedder.embed_batch(texts)
+                }).await??;
+
+                // 3. LanceDB Store
+                let mut lance_records = Vec::with_capacity(markets_to_embed.len());
+                for (market, vector) in markets_to_embed.iter().zip(vectors.into_iter()) {
+                    lance_records.push(MarketEmbedding {
+                        id: format!("{:?}:{}", exchange, market.ticker),
+                        vector,
+                        ticker: market.ticker.clone(),
+                        source: market.source.clone(),
+                        status: market.status.clone(),
+                        title: market.title.clone(),
+                        description: market.description.clone(),
+                        outcomes: market.outcomes.join(", "),
+                    });
+                }
 
-            // 3. LanceDB Store
-            let mut lance_records = Vec::with_capacity(batch_size);
-            for (market, vector) in response.markets.iter().zip(vectors.into_iter()) {
-                lance_records.push(MarketEmbedding {
-                    id: format!("{:?}:{}", exchange, market.ticker),
-                    vector,
-                    ticker: market.ticker.clone(),
-                    source: market.source.clone(),
-                    title: market.title.clone(),
-                    description: market.description.clone(),
-                    outcomes: market.outcomes.join(", "),
-                });
+                self.lance_store.add_markets(lance_records).await?;
             }
-            
-            self.lance_store.add_markets(lance_records).await?;
 
             // Pagination logic
             if response.cursor.is_empty() || Some(&response.cursor) == cursor.as_ref() {
+                self.storage.lock().await.clear_checkpoint(&exchange_key, "markets", &status_key).await?;
                 break;
             }
             cursor = Some(response.cursor);
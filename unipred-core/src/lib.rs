@@ -1,21 +1,87 @@
 pub mod clients;
 pub mod commands;
+pub mod config;
 pub mod domain;
+pub mod error;
+pub mod ml;
+pub mod storage;
 
-use clients::kalshi::{Kalshi, TradingEnvironment};
+pub use config::Config;
+pub use error::UnipredError;
+
+pub mod proto {
+    include!(concat!(env!("OUT_DIR"), "/unipred.rs"));
+}
+
+use anyhow::{Context, Result};
+use clients::kalshi::Kalshi;
 use clients::polymarket::ClobClient;
+use std::sync::Arc;
+use storage::duck::DuckStore;
+use tokio::sync::Mutex;
 
 pub struct UnipredCore {
     pub kalshi: Kalshi,
     pub polymarket: ClobClient,
+    pub duck_store: Arc<Mutex<DuckStore>>,
+    pub config: Config,
 }
 
 impl UnipredCore {
-    pub fn new(_config: String) -> Self {
-        // In a real app, parse config to set up auth/environments
-        Self {
-            kalshi: Kalshi::new(TradingEnvironment::DemoMode),
-            polymarket: ClobClient::new("https://clob.polymarket.com"),
+    /// Build a core from a JSON config file (`config_path`, e.g. `markets.json`) plus a
+    /// companion `.env` file for credentials. Picks demo vs. prod for Kalshi, honors any
+    /// API endpoint overrides, and logs in with the configured API key if one is present.
+    pub fn new(config_path: String) -> Result<Self> {
+        let config = Config::from_env_and_file(&config_path, ".env")
+            .context("Failed to load UnipredCore config")?;
+
+        let mut kalshi = Kalshi::new(config.kalshi.environment());
+        if let Some(base) = &config.kalshi.api_base_override {
+            kalshi = kalshi.with_base_url(base.clone());
+        }
+
+        if let (Some(key_id), Some(private_key)) =
+            (config.kalshi.resolve_api_key_id(), &config.kalshi.private_key)
+        {
+            let key_id = key_id?;
+            let pem = private_key.resolve()?;
+
+            futures::executor::block_on(kalshi.login_apikey_from_pem(&key_id, &pem))
+                .context("Failed to authenticate Kalshi with configured API key")?;
         }
+
+        let polymarket_base = config
+            .polymarket
+            .base_url
+            .clone()
+            .unwrap_or_else(|| "https://clob.polymarket.com".to_string());
+
+        let duck_store =
+            DuckStore::open("unipred.duckdb").context("Failed to open default DuckDB store")?;
+
+        Ok(Self {
+            kalshi,
+            polymarket: ClobClient::new(&polymarket_base),
+            duck_store: Arc::new(Mutex::new(duck_store)),
+            config,
+        })
+    }
+
+    /// Build a core with an empty, unloaded `Config` -- demo-mode Kalshi, default
+    /// Polymarket base URL, no curated markets -- and no login performed. Callers are
+    /// expected to authenticate themselves afterward (e.g. integration tests that log in
+    /// with credentials pulled from a `.env` file rather than a JSON config).
+    pub fn new_unconfigured() -> Result<Self> {
+        let config = Config::default();
+        let kalshi = Kalshi::new(config.kalshi.environment());
+        let duck_store =
+            DuckStore::open("unipred.duckdb").context("Failed to open default DuckDB store")?;
+
+        Ok(Self {
+            kalshi,
+            polymarket: ClobClient::new("https://clob.polymarket.com"),
+            duck_store: Arc::new(Mutex::new(duck_store)),
+            config,
+        })
     }
 }
@@ -0,0 +1,57 @@
+use crate::proto::{FetchedEvent, FetchedMarket};
+use async_trait::async_trait;
+use anyhow::Result;
+use std::collections::HashSet;
+
+/// Common persistence surface for the "current snapshot" tables (`markets`/`events`) plus the
+/// resumable-ingestion bookkeeping (`ingestion_checkpoints`, `content_hash`) that
+/// `IngestionEngine` needs, implemented by both the local-analysis `DuckStore` and the
+/// shared-service `PgStore` so the same ingestion pipeline (`FetchMarkets`/`FetchEvents` ->
+/// `IngestionEngine`) can run against either one, selected via `Config::storage`. Upsert
+/// semantics on `(ticker, source)` must match across backends.
+#[async_trait]
+pub trait StorageBackend: Send + Sync {
+    /// Create the `markets`/`events`/`ingestion_checkpoints` tables if they don't already exist.
+    async fn init_schema(&mut self) -> Result<()>;
+
+    /// Upsert a batch of markets, keyed by `(ticker, source)`.
+    async fn insert_batch(&mut self, markets: &[FetchedMarket]) -> Result<()>;
+
+    /// Upsert a batch of events, keyed by `(ticker, source)`.
+    async fn insert_events_batch(&mut self, events: &[FetchedEvent]) -> Result<()>;
+
+    /// Same as `insert_batch`, but also persists the ingestion checkpoint for `(exchange,
+    /// "markets", status)` as part of the same write, so a crash between the two is impossible.
+    async fn insert_batch_and_checkpoint(
+        &mut self,
+        markets: &[FetchedMarket],
+        exchange: &str,
+        status: &str,
+        cursor: &str,
+        page_count: i64,
+    ) -> Result<()>;
+
+    /// Same as `insert_events_batch`, but also persists the ingestion checkpoint for
+    /// `(exchange, "events", status)` as part of the same write.
+    async fn insert_events_batch_and_checkpoint(
+        &mut self,
+        events: &[FetchedEvent],
+        exchange: &str,
+        status: &str,
+        cursor: &str,
+        page_count: i64,
+    ) -> Result<()>;
+
+    /// Load the last checkpointed cursor/page_count for an ingestion stream, if any.
+    async fn load_checkpoint(&mut self, exchange: &str, kind: &str, status: &str) -> Result<Option<(String, i64)>>;
+
+    /// Clear a checkpoint on clean completion of its ingestion stream.
+    async fn clear_checkpoint(&mut self, exchange: &str, kind: &str, status: &str) -> Result<()>;
+
+    /// Returns the `(ticker, source)` pairs among `markets` whose title/description no longer
+    /// match the `content_hash` already stored for them (including markets not seen before).
+    /// Must be called *before* `insert_batch`/`insert_batch_and_checkpoint`, which overwrite
+    /// `content_hash` with the new value -- used by `IngestionFilter::refresh_only` to skip
+    /// re-embedding markets whose content hasn't actually changed.
+    async fn changed_content_tickers(&mut self, markets: &[FetchedMarket]) -> Result<HashSet<(String, String)>>;
+}
@@ -0,0 +1,20 @@
+use crate::clients::kalshi::tick::Tick;
+
+/// Resolutions derived from the 1-minute base resolution, alongside their length in seconds.
+pub const BASE_RESOLUTION: &str = "1m";
+pub const BASE_RESOLUTION_SECS: i64 = 60;
+pub const DERIVED_RESOLUTIONS: &[(&str, i64)] = &[("5m", 300), ("1h", 3600), ("1d", 86400)];
+
+/// A single OHLCV bucket for a `(ticker, source, resolution)` series. Derivation from raw
+/// ticks/trades happens in SQL (`DuckStore::build_candles_from_ticks`,
+/// `DuckStore::rebuild_candles_from_trades`); this struct is just the row shape passed to
+/// `DuckStore::upsert_candles`.
+#[derive(Debug, Clone, Copy)]
+pub struct Candle {
+    pub bucket_start: i64,
+    pub open: Tick,
+    pub high: Tick,
+    pub low: Tick,
+    pub close: Tick,
+    pub volume: i64,
+}
@@ -1,6 +1,11 @@
 use anyhow::Result;
+use async_trait::async_trait;
 use duckdb::{params, Connection};
 use crate::proto::{FetchedMarket, FetchedEvent};
+use crate::storage::backend::StorageBackend;
+use crate::clients::kalshi::tick::Tick;
+use crate::storage::candles::Candle;
+use crate::storage::trades::RawTrade;
 
 pub struct DuckStore {
     conn: Connection,
@@ -30,12 +35,28 @@ impl DuckStore {
                 volume VARCHAR,
                 liquidity VARCHAR,
                 url VARCHAR,
+                content_hash VARCHAR,
                 ingested_at TIMESTAMP DEFAULT current_timestamp,
                 PRIMARY KEY (ticker, source)
             )",
             [],
         )?;
 
+        // Append-only audit log of prior `status`/`volume` values, written whenever an
+        // incoming market upsert would overwrite a row whose status or volume differs --
+        // see `merge_markets_staging`.
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS market_history (
+                ticker VARCHAR,
+                source VARCHAR,
+                title VARCHAR,
+                status VARCHAR,
+                volume VARCHAR,
+                changed_at TIMESTAMP DEFAULT current_timestamp
+            )",
+            [],
+        )?;
+
         self.conn.execute(
             "CREATE TABLE IF NOT EXISTS events (
                 ticker VARCHAR,
@@ -50,25 +71,86 @@ impl DuckStore {
             )",
             [],
         )?;
+
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS candles (
+                ticker VARCHAR,
+                source VARCHAR,
+                resolution VARCHAR,
+                bucket_start TIMESTAMP,
+                open VARCHAR,
+                high VARCHAR,
+                low VARCHAR,
+                close VARCHAR,
+                volume BIGINT,
+                PRIMARY KEY (ticker, source, resolution, bucket_start)
+            )",
+            [],
+        )?;
+
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS trades (
+                ticker VARCHAR,
+                source VARCHAR,
+                trade_id VARCHAR,
+                price INTEGER,
+                size INTEGER,
+                side VARCHAR,
+                traded_at TIMESTAMP,
+                PRIMARY KEY (ticker, source, trade_id)
+            )",
+            [],
+        )?;
+
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS price_ticks (
+                source VARCHAR,
+                ticker VARCHAR,
+                ts TIMESTAMP,
+                price INTEGER,
+                cumulative_volume BIGINT,
+                PRIMARY KEY (source, ticker, ts)
+            )",
+            [],
+        )?;
+
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS ingestion_checkpoints (
+                exchange VARCHAR,
+                kind VARCHAR,
+                status VARCHAR,
+                cursor VARCHAR,
+                page_count BIGINT,
+                updated_at TIMESTAMP DEFAULT current_timestamp,
+                PRIMARY KEY (exchange, kind, status)
+            )",
+            [],
+        )?;
         Ok(())
     }
 
-    /// Batch insert or replace markets.
-    pub fn insert_batch(&mut self, markets: &[FetchedMarket]) -> Result<()> {
-        let tx = self.conn.transaction()?;
-        {
-            let mut stmt = tx.prepare(
-                "INSERT OR REPLACE INTO markets (
-                    ticker, source, title, status, description, outcomes, 
-                    start_date, end_date, volume, liquidity, url
-                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
-            )?;
+    /// Append `markets` to a staging table and merge it into `markets` within `tx`, but does
+    /// not commit -- shared by `insert_batch` and `insert_batch_and_checkpoint` so the latter
+    /// can fold a checkpoint write into the same transaction.
+    ///
+    /// Goes through a `markets_staging` table rather than `INSERT OR REPLACE`-ing row by row:
+    /// DuckDB's Appender is a columnar API and each `execute()` on a prepared statement is its
+    /// own round trip, so for the multi-thousand-row backfills this crate targets, appending to
+    /// staging and merging in one `INSERT ... SELECT ... ON CONFLICT` is dramatically faster.
+    fn merge_markets_staging(tx: &duckdb::Transaction, markets: &[FetchedMarket]) -> Result<()> {
+        tx.execute("DROP TABLE IF EXISTS markets_staging", [])?;
+        tx.execute(
+            "CREATE TEMP TABLE markets_staging AS SELECT * FROM markets WHERE false",
+            [],
+        )?;
 
+        {
+            let mut appender = tx.appender("markets_staging")?;
             for m in markets {
                 // Join outcomes into a single string for storage
                 let outcomes_str = m.outcomes.join(", ");
-                
-                stmt.execute(params![
+
+                appender.append_row(params![
                     m.ticker,
                     m.source,
                     m.title,
@@ -79,38 +161,677 @@ impl DuckStore {
                     m.end_date,
                     m.volume,
                     m.liquidity,
-                    m.url
+                    m.url,
+                    duckdb::types::Null,
+                    duckdb::types::Null,
                 ])?;
             }
+            appender.flush()?;
+        }
+
+        // Record the prior status/volume into the audit log before the upsert below
+        // overwrites it -- only for rows that actually changed, so re-ingesting an
+        // unchanged market doesn't spam the log.
+        tx.execute(
+            "INSERT INTO market_history (ticker, source, title, status, volume)
+            SELECT m.ticker, m.source, m.title, m.status, m.volume
+            FROM markets m
+            JOIN markets_staging s ON m.ticker = s.ticker AND m.source = s.source
+            WHERE m.status IS DISTINCT FROM s.status OR m.volume IS DISTINCT FROM s.volume",
+            [],
+        )?;
+
+        tx.execute(
+            "INSERT INTO markets (
+                ticker, source, title, status, description, outcomes,
+                start_date, end_date, volume, liquidity, url, content_hash
+            )
+            SELECT ticker, source, title, status, description, outcomes,
+                start_date, end_date, volume, liquidity, url,
+                md5(title || '|' || description)
+            FROM markets_staging
+            ON CONFLICT (ticker, source) DO UPDATE SET
+                title = excluded.title,
+                status = excluded.status,
+                description = excluded.description,
+                outcomes = excluded.outcomes,
+                start_date = excluded.start_date,
+                end_date = excluded.end_date,
+                volume = excluded.volume,
+                liquidity = excluded.liquidity,
+                url = excluded.url,
+                content_hash = excluded.content_hash",
+            [],
+        )?;
+        tx.execute("DROP TABLE markets_staging", [])?;
+        Ok(())
+    }
+
+    /// Returns the `(ticker, source)` pairs among `markets` whose title/description no longer
+    /// match the `content_hash` already stored for them (including markets not seen before).
+    /// Must be called *before* `insert_batch`/`insert_batch_and_checkpoint`, which overwrite
+    /// `content_hash` with the new value -- used by `IngestionFilter::refresh_only` to skip
+    /// re-embedding markets whose content hasn't actually changed.
+    pub fn changed_content_tickers(
+        &self,
+        markets: &[FetchedMarket],
+    ) -> Result<std::collections::HashSet<(String, String)>> {
+        let mut changed = std::collections::HashSet::new();
+        let mut stmt = self.conn.prepare(
+            "SELECT 1 FROM markets WHERE ticker = ? AND source = ? AND content_hash = md5(? || '|' || ?)",
+        )?;
+        for m in markets {
+            let mut rows = stmt.query(params![m.ticker, m.source, m.title, m.description])?;
+            if rows.next()?.is_none() {
+                changed.insert((m.ticker.clone(), m.source.clone()));
+            }
+        }
+        Ok(changed)
+    }
+
+    /// Batch insert or replace markets.
+    pub fn insert_batch(&mut self, markets: &[FetchedMarket]) -> Result<()> {
+        if markets.is_empty() {
+            return Ok(());
         }
+
+        let tx = self.conn.transaction()?;
+        Self::merge_markets_staging(&tx, markets)?;
         tx.commit()?;
         Ok(())
     }
 
-    /// Batch insert or replace events.
-    pub fn insert_events_batch(&mut self, events: &[FetchedEvent]) -> Result<()> {
+    /// Same as `insert_batch`, but persists the ingestion checkpoint for `(exchange, "markets",
+    /// status)` in the same transaction, so a crash between the two is impossible: either both
+    /// land or neither does.
+    pub fn insert_batch_and_checkpoint(
+        &mut self,
+        markets: &[FetchedMarket],
+        exchange: &str,
+        status: &str,
+        cursor: &str,
+        page_count: i64,
+    ) -> Result<()> {
+        if markets.is_empty() {
+            return Ok(());
+        }
+
         let tx = self.conn.transaction()?;
-        {
-            let mut stmt = tx.prepare(
-                "INSERT OR REPLACE INTO events (
-                    ticker, source, title, description,
-                    start_date, end_date, url
-                ) VALUES (?, ?, ?, ?, ?, ?, ?)",
-            )?;
+        Self::merge_markets_staging(&tx, markets)?;
+        Self::save_checkpoint_tx(&tx, exchange, "markets", status, cursor, page_count)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Append `events` to a staging table and merge it into `events` within `tx`; see
+    /// `merge_markets_staging` for why this isn't inlined into `insert_events_batch`.
+    fn merge_events_staging(tx: &duckdb::Transaction, events: &[FetchedEvent]) -> Result<()> {
+        tx.execute("DROP TABLE IF EXISTS events_staging", [])?;
+        tx.execute(
+            "CREATE TEMP TABLE events_staging AS SELECT * FROM events WHERE false",
+            [],
+        )?;
 
+        {
+            let mut appender = tx.appender("events_staging")?;
             for e in events {
-                stmt.execute(params![
+                appender.append_row(params![
                     e.ticker,
                     e.source,
                     e.title,
                     e.description,
                     e.start_date,
                     e.end_date,
-                    e.url
+                    e.url,
+                    duckdb::types::Null,
+                ])?;
+            }
+            appender.flush()?;
+        }
+
+        tx.execute(
+            "INSERT INTO events (
+                ticker, source, title, description, start_date, end_date, url
+            )
+            SELECT ticker, source, title, description, start_date, end_date, url
+            FROM events_staging
+            ON CONFLICT (ticker, source) DO UPDATE SET
+                title = excluded.title,
+                description = excluded.description,
+                start_date = excluded.start_date,
+                end_date = excluded.end_date,
+                url = excluded.url",
+            [],
+        )?;
+        tx.execute("DROP TABLE events_staging", [])?;
+        Ok(())
+    }
+
+    /// Batch insert or replace events, via the same Appender + staging-table merge as
+    /// `insert_batch`.
+    pub fn insert_events_batch(&mut self, events: &[FetchedEvent]) -> Result<()> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let tx = self.conn.transaction()?;
+        Self::merge_events_staging(&tx, events)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Same as `insert_events_batch`, but persists the ingestion checkpoint for `(exchange,
+    /// "events", status)` in the same transaction.
+    pub fn insert_events_batch_and_checkpoint(
+        &mut self,
+        events: &[FetchedEvent],
+        exchange: &str,
+        status: &str,
+        cursor: &str,
+        page_count: i64,
+    ) -> Result<()> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let tx = self.conn.transaction()?;
+        Self::merge_events_staging(&tx, events)?;
+        Self::save_checkpoint_tx(&tx, exchange, "events", status, cursor, page_count)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Load the last checkpointed cursor/page_count for an ingestion stream, if any.
+    pub fn load_checkpoint(&self, exchange: &str, kind: &str, status: &str) -> Result<Option<(String, i64)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT cursor, page_count FROM ingestion_checkpoints
+             WHERE exchange = ? AND kind = ? AND status = ?",
+        )?;
+        let mut rows = stmt.query(params![exchange, kind, status])?;
+        match rows.next()? {
+            Some(row) => {
+                let cursor: String = row.get(0)?;
+                let page_count: i64 = row.get(1)?;
+                Ok(Some((cursor, page_count)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Clear a checkpoint on clean completion of its ingestion stream.
+    pub fn clear_checkpoint(&mut self, exchange: &str, kind: &str, status: &str) -> Result<()> {
+        self.conn.execute(
+            "DELETE FROM ingestion_checkpoints WHERE exchange = ? AND kind = ? AND status = ?",
+            params![exchange, kind, status],
+        )?;
+        Ok(())
+    }
+
+    fn save_checkpoint_tx(
+        tx: &duckdb::Transaction,
+        exchange: &str,
+        kind: &str,
+        status: &str,
+        cursor: &str,
+        page_count: i64,
+    ) -> Result<()> {
+        tx.execute(
+            "INSERT OR REPLACE INTO ingestion_checkpoints (exchange, kind, status, cursor, page_count)
+             VALUES (?, ?, ?, ?, ?)",
+            params![exchange, kind, status, cursor, page_count],
+        )?;
+        Ok(())
+    }
+
+    /// Upsert a batch of `(ticker, source, resolution, candle)` buckets, e.g. as produced by
+    /// `build_candles_from_ticks`/`rebuild_candles_from_trades` or `BackfillCandles`.
+    /// Re-running over overlapping buckets is safe: the primary key on `(ticker, source,
+    /// resolution, bucket_start)` makes this idempotent.
+    pub fn upsert_candles(
+        &mut self,
+        resolution: &str,
+        candles: &[(String, String, Candle)],
+    ) -> Result<()> {
+        let tx = self.conn.transaction()?;
+        {
+            let mut stmt = tx.prepare(
+                "INSERT OR REPLACE INTO candles (
+                    ticker, source, resolution, bucket_start, open, high, low, close, volume
+                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            )?;
+
+            for (ticker, source, candle) in candles {
+                let bucket_start = chrono::DateTime::from_timestamp(candle.bucket_start, 0)
+                    .map(|dt| dt.naive_utc())
+                    .ok_or_else(|| anyhow::anyhow!("invalid bucket_start: {}", candle.bucket_start))?;
+
+                stmt.execute(params![
+                    ticker,
+                    source,
+                    resolution,
+                    bucket_start,
+                    candle.open.to_dollars_string(),
+                    candle.high.to_dollars_string(),
+                    candle.low.to_dollars_string(),
+                    candle.close.to_dollars_string(),
+                    candle.volume,
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Read back candles for a `(ticker, source, resolution)` series, optionally bounded by
+    /// an inclusive `[start, end]` epoch-second range, ordered oldest-first.
+    pub fn get_candles(
+        &self,
+        ticker: &str,
+        source: &str,
+        resolution: &str,
+        start: Option<i64>,
+        end: Option<i64>,
+    ) -> Result<Vec<(String, String, Candle)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT epoch(bucket_start)::BIGINT, open, high, low, close, volume
+             FROM candles
+             WHERE ticker = ? AND source = ? AND resolution = ?
+               AND (? IS NULL OR epoch(bucket_start) >= ?)
+               AND (? IS NULL OR epoch(bucket_start) <= ?)
+             ORDER BY bucket_start ASC",
+        )?;
+
+        let rows = stmt.query_map(
+            params![ticker, source, resolution, start, start, end, end],
+            |row| {
+                let bucket_start: i64 = row.get(0)?;
+                let open: String = row.get(1)?;
+                let high: String = row.get(2)?;
+                let low: String = row.get(3)?;
+                let close: String = row.get(4)?;
+                let volume: i64 = row.get(5)?;
+                Ok((bucket_start, open, high, low, close, volume))
+            },
+        )?;
+
+        let mut out = Vec::new();
+        for row in rows {
+            let (bucket_start, open, high, low, close, volume) = row?;
+            let candle = Candle {
+                bucket_start,
+                open: crate::clients::kalshi::tick::Tick::from_dollars_round_down(&open)
+                    .unwrap_or(crate::clients::kalshi::tick::Tick(0)),
+                high: crate::clients::kalshi::tick::Tick::from_dollars_round_down(&high)
+                    .unwrap_or(crate::clients::kalshi::tick::Tick(0)),
+                low: crate::clients::kalshi::tick::Tick::from_dollars_round_down(&low)
+                    .unwrap_or(crate::clients::kalshi::tick::Tick(0)),
+                close: crate::clients::kalshi::tick::Tick::from_dollars_round_down(&close)
+                    .unwrap_or(crate::clients::kalshi::tick::Tick(0)),
+                volume,
+            };
+            out.push((ticker.to_string(), source.to_string(), candle));
+        }
+
+        Ok(out)
+    }
+
+    /// Upsert a batch of raw executed trades, keyed by `(ticker, source, trade_id)` so a
+    /// re-backfill over an overlapping time range does not double-count volume.
+    pub fn backfill_trades(&mut self, trades: &[RawTrade]) -> Result<()> {
+        let tx = self.conn.transaction()?;
+        {
+            let mut stmt = tx.prepare(
+                "INSERT OR REPLACE INTO trades (
+                    ticker, source, trade_id, price, size, side, traded_at
+                ) VALUES (?, ?, ?, ?, ?, ?, ?)",
+            )?;
+
+            for t in trades {
+                let traded_at = chrono::DateTime::from_timestamp(t.traded_at, 0)
+                    .map(|dt| dt.naive_utc())
+                    .ok_or_else(|| anyhow::anyhow!("invalid traded_at: {}", t.traded_at))?;
+
+                stmt.execute(params![
+                    t.ticker,
+                    t.source,
+                    t.trade_id,
+                    t.price.0,
+                    t.size,
+                    t.side,
+                    traded_at,
                 ])?;
             }
         }
         tx.commit()?;
         Ok(())
     }
+
+    /// Derive candles for `resolution`/`resolution_secs` from the `trades` table, as a second
+    /// pass independent of `backfill_trades`. Safe to re-run over already-downloaded trades
+    /// after a schema change: `open`/`close` are picked by earliest/latest `traded_at` within
+    /// each bucket (not insertion order), so re-deriving never double-counts.
+    pub fn rebuild_candles_from_trades(&mut self, resolution: &str, resolution_secs: i64) -> Result<usize> {
+        let mut stmt = self.conn.prepare(
+            "SELECT
+                ticker,
+                source,
+                CAST(epoch(bucket_start) AS BIGINT) AS bucket_start,
+                arg_min(price, traded_at) AS open,
+                max(price) AS high,
+                min(price) AS low,
+                arg_max(price, traded_at) AS close,
+                sum(size) AS volume
+             FROM (
+                SELECT *, to_timestamp(
+                    CAST(epoch(traded_at) AS BIGINT) - CAST(epoch(traded_at) AS BIGINT) % ?
+                ) AS bucket_start
+                FROM trades
+             )
+             GROUP BY ticker, source, bucket_start",
+        )?;
+
+        let rows = stmt.query_map(params![resolution_secs], |row| {
+            let ticker: String = row.get(0)?;
+            let source: String = row.get(1)?;
+            let bucket_start: i64 = row.get(2)?;
+            let open: i32 = row.get(3)?;
+            let high: i32 = row.get(4)?;
+            let low: i32 = row.get(5)?;
+            let close: i32 = row.get(6)?;
+            let volume: i64 = row.get(7)?;
+            Ok((ticker, source, bucket_start, open, high, low, close, volume))
+        })?;
+
+        let mut candles = Vec::new();
+        for row in rows {
+            let (ticker, source, bucket_start, open, high, low, close, volume) = row?;
+            candles.push((
+                ticker,
+                source,
+                Candle {
+                    bucket_start,
+                    open: crate::clients::kalshi::tick::Tick(open),
+                    high: crate::clients::kalshi::tick::Tick(high),
+                    low: crate::clients::kalshi::tick::Tick(low),
+                    close: crate::clients::kalshi::tick::Tick(close),
+                    volume,
+                },
+            ));
+        }
+
+        let count = candles.len();
+        self.upsert_candles(resolution, &candles)?;
+        Ok(count)
+    }
+
+    /// Record a single quote snapshot (the quote's price -- last trade price for Kalshi,
+    /// bid/ask mid for Polymarket -- plus cumulative volume) from `GetMarketQuote`
+    /// or an ingestion pass. Keyed by `(source, ticker, ts)` so re-recording the same
+    /// second-resolution snapshot is idempotent rather than appending a duplicate tick.
+    pub fn insert_price_tick(
+        &mut self,
+        source: &str,
+        ticker: &str,
+        ts: i64,
+        price: Tick,
+        cumulative_volume: i64,
+    ) -> Result<()> {
+        let ts = chrono::DateTime::from_timestamp(ts, 0)
+            .map(|dt| dt.naive_utc())
+            .ok_or_else(|| anyhow::anyhow!("invalid tick timestamp: {}", ts))?;
+
+        self.conn.execute(
+            "INSERT OR REPLACE INTO price_ticks (source, ticker, ts, price, cumulative_volume)
+             VALUES (?, ?, ?, ?, ?)",
+            params![source, ticker, ts, price.0, cumulative_volume],
+        )?;
+        Ok(())
+    }
+
+    /// Derive candles for `resolution`/`resolution_secs` from the `price_ticks` table.
+    ///
+    /// `open`/`close` are picked by earliest/latest `ts` within each bucket (not insertion
+    /// order), so re-deriving over overlapping tick ranges never double-counts. `volume` is
+    /// summed from per-tick deltas of `cumulative_volume` (Kalshi reports volume cumulatively),
+    /// with each delta clamped to zero so a counter reset never contributes negative volume.
+    pub fn build_candles_from_ticks(&mut self, resolution: &str, resolution_secs: i64) -> Result<usize> {
+        let mut stmt = self.conn.prepare(
+            "WITH deltas AS (
+                SELECT
+                    ticker,
+                    source,
+                    ts,
+                    price,
+                    GREATEST(
+                        cumulative_volume - COALESCE(
+                            LAG(cumulative_volume) OVER (PARTITION BY ticker, source ORDER BY ts),
+                            cumulative_volume
+                        ),
+                        0
+                    ) AS volume_delta,
+                    to_timestamp(
+                        CAST(epoch(ts) AS BIGINT) - CAST(epoch(ts) AS BIGINT) % ?
+                    ) AS bucket_start
+                FROM price_ticks
+             )
+             SELECT
+                ticker,
+                source,
+                CAST(epoch(bucket_start) AS BIGINT) AS bucket_start,
+                arg_min(price, ts) AS open,
+                max(price) AS high,
+                min(price) AS low,
+                arg_max(price, ts) AS close,
+                sum(volume_delta) AS volume
+             FROM deltas
+             GROUP BY ticker, source, bucket_start",
+        )?;
+
+        let rows = stmt.query_map(params![resolution_secs], |row| {
+            let ticker: String = row.get(0)?;
+            let source: String = row.get(1)?;
+            let bucket_start: i64 = row.get(2)?;
+            let open: i32 = row.get(3)?;
+            let high: i32 = row.get(4)?;
+            let low: i32 = row.get(5)?;
+            let close: i32 = row.get(6)?;
+            let volume: i64 = row.get(7)?;
+            Ok((ticker, source, bucket_start, open, high, low, close, volume))
+        })?;
+
+        let mut candles = Vec::new();
+        for row in rows {
+            let (ticker, source, bucket_start, open, high, low, close, volume) = row?;
+            candles.push((
+                ticker,
+                source,
+                Candle {
+                    bucket_start,
+                    open: Tick(open),
+                    high: Tick(high),
+                    low: Tick(low),
+                    close: Tick(close),
+                    volume,
+                },
+            ));
+        }
+
+        let count = candles.len();
+        self.upsert_candles(resolution, &candles)?;
+        Ok(count)
+    }
+
+    /// Join `markets` against the latest `price_ticks` row (last price) and latest `1d`
+    /// `candles` row (high/low) per `(ticker, source)`, optionally restricted to one
+    /// exchange/status. Backs `ExportTickers`.
+    pub fn export_tickers(&self, source: Option<&str>, status: Option<&str>) -> Result<Vec<TickerRow>> {
+        let mut stmt = self.conn.prepare(
+            "WITH latest_tick AS (
+                SELECT
+                    ticker, source, price,
+                    ROW_NUMBER() OVER (PARTITION BY ticker, source ORDER BY ts DESC) AS rn
+                FROM price_ticks
+             ),
+             latest_candle AS (
+                SELECT
+                    ticker, source, high, low,
+                    ROW_NUMBER() OVER (PARTITION BY ticker, source ORDER BY bucket_start DESC) AS rn
+                FROM candles
+                WHERE resolution = '1d'
+             )
+             SELECT
+                m.ticker, m.source, m.status, m.volume,
+                lt.price, lc.high, lc.low
+             FROM markets m
+             LEFT JOIN latest_tick lt ON lt.ticker = m.ticker AND lt.source = m.source AND lt.rn = 1
+             LEFT JOIN latest_candle lc ON lc.ticker = m.ticker AND lc.source = m.source AND lc.rn = 1
+             WHERE (? IS NULL OR m.source = ?)
+               AND (? IS NULL OR m.status = ?)",
+        )?;
+
+        let rows = stmt.query_map(params![source, source, status, status], |row| {
+            let ticker: String = row.get(0)?;
+            let source: String = row.get(1)?;
+            let status: String = row.get(2)?;
+            let volume: String = row.get(3)?;
+            let last_price: Option<i32> = row.get(4)?;
+            let high: Option<i32> = row.get(5)?;
+            let low: Option<i32> = row.get(6)?;
+            Ok((ticker, source, status, volume, last_price, high, low))
+        })?;
+
+        let mut out = Vec::new();
+        for row in rows {
+            let (ticker, source, status, volume, last_price, high, low) = row?;
+            out.push(TickerRow {
+                ticker,
+                source,
+                status,
+                volume,
+                last_price: last_price.map(Tick),
+                high: high.map(Tick),
+                low: low.map(Tick),
+            });
+        }
+
+        Ok(out)
+    }
+}
+
+/// One row of `export_tickers`: a market joined against its latest tick/candle data.
+pub struct TickerRow {
+    pub ticker: String,
+    pub source: String,
+    pub status: String,
+    pub volume: String,
+    pub last_price: Option<Tick>,
+    pub high: Option<Tick>,
+    pub low: Option<Tick>,
+}
+
+#[async_trait]
+impl StorageBackend for DuckStore {
+    async fn init_schema(&mut self) -> Result<()> {
+        // DuckDB access is synchronous; the trait is async purely so callers can hold
+        // a `Box<dyn StorageBackend>` shared with the Postgres backend.
+        self.init_schema()
+    }
+
+    async fn insert_batch(&mut self, markets: &[FetchedMarket]) -> Result<()> {
+        self.insert_batch(markets)
+    }
+
+    async fn insert_events_batch(&mut self, events: &[FetchedEvent]) -> Result<()> {
+        self.insert_events_batch(events)
+    }
+
+    async fn insert_batch_and_checkpoint(
+        &mut self,
+        markets: &[FetchedMarket],
+        exchange: &str,
+        status: &str,
+        cursor: &str,
+        page_count: i64,
+    ) -> Result<()> {
+        self.insert_batch_and_checkpoint(markets, exchange, status, cursor, page_count)
+    }
+
+    async fn insert_events_batch_and_checkpoint(
+        &mut self,
+        events: &[FetchedEvent],
+        exchange: &str,
+        status: &str,
+        cursor: &str,
+        page_count: i64,
+    ) -> Result<()> {
+        self.insert_events_batch_and_checkpoint(events, exchange, status, cursor, page_count)
+    }
+
+    async fn load_checkpoint(&mut self, exchange: &str, kind: &str, status: &str) -> Result<Option<(String, i64)>> {
+        self.load_checkpoint(exchange, kind, status)
+    }
+
+    async fn clear_checkpoint(&mut self, exchange: &str, kind: &str, status: &str) -> Result<()> {
+        self.clear_checkpoint(exchange, kind, status)
+    }
+
+    async fn changed_content_tickers(&mut self, markets: &[FetchedMarket]) -> Result<std::collections::HashSet<(String, String)>> {
+        self.changed_content_tickers(markets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn synthetic_market(i: usize, title: &str) -> FetchedMarket {
+        FetchedMarket {
+            ticker: format!("TICK-{:06}", i),
+            source: "Kalshi".to_string(),
+            title: title.to_string(),
+            status: "active".to_string(),
+            description: "synthetic market for bulk-insert benchmark".to_string(),
+            outcomes: vec!["Yes".to_string(), "No".to_string()],
+            start_date: "2026-01-01".to_string(),
+            end_date: "2026-12-31".to_string(),
+            volume: "0".to_string(),
+            liquidity: "0".to_string(),
+            url: format!("https://example.com/{}", i),
+        }
+    }
+
+    #[test]
+    fn test_insert_batch_50k_and_conflict_dedup() {
+        let mut store = DuckStore::open(":memory:").unwrap();
+
+        let markets: Vec<FetchedMarket> = (0..50_000).map(|i| synthetic_market(i, "v1")).collect();
+        store.insert_batch(&markets).unwrap();
+
+        let count: i64 = store
+            .conn
+            .query_row("SELECT count(*) FROM markets", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 50_000);
+
+        // Re-insert the same tickers with a changed title; the (ticker, source) primary key
+        // should win on conflict rather than duplicating rows.
+        let updated: Vec<FetchedMarket> = (0..50_000).map(|i| synthetic_market(i, "v2")).collect();
+        store.insert_batch(&updated).unwrap();
+
+        let count: i64 = store
+            .conn
+            .query_row("SELECT count(*) FROM markets", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 50_000);
+
+        let title: String = store
+            .conn
+            .query_row(
+                "SELECT title FROM markets WHERE ticker = 'TICK-000042' AND source = 'Kalshi'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(title, "v2");
+    }
 }
\ No newline at end of file
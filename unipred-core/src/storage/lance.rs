@@ -7,6 +7,38 @@ use futures::TryStreamExt;
 use lancedb::{Connection, index::Index, query::{ExecutableQuery, QueryBase}};
 use std::sync::Arc;
 
+/// Restricts `LanceStore::search_filtered` to rows matching all of the given
+/// (stored) columns. Fields left as `None` are not filtered on.
+#[derive(Debug, Clone, Default)]
+pub struct MarketSearchFilter {
+    pub source: Option<String>,
+    pub status: Option<String>,
+    pub ticker: Option<String>,
+}
+
+impl MarketSearchFilter {
+    /// Render as a LanceDB SQL predicate suitable for `.only_if(...)`, or
+    /// `None` if no fields are set.
+    fn to_predicate(&self) -> Option<String> {
+        let mut clauses = Vec::new();
+        if let Some(source) = &self.source {
+            clauses.push(format!("source = '{}'", source.replace('\'', "''")));
+        }
+        if let Some(status) = &self.status {
+            clauses.push(format!("status = '{}'", status.replace('\'', "''")));
+        }
+        if let Some(ticker) = &self.ticker {
+            clauses.push(format!("ticker = '{}'", ticker.replace('\'', "''")));
+        }
+
+        if clauses.is_empty() {
+            None
+        } else {
+            Some(clauses.join(" AND "))
+        }
+    }
+}
+
 pub const VECTOR_DIM: i32 = 384; // Using all-MiniLM-L6-v2 dimension
 pub const TABLE_NAME: &str = "markets";
 
@@ -20,6 +52,7 @@ pub struct MarketEmbedding {
     pub vector: Vec<f32>,
     pub ticker: String,
     pub source: String,
+    pub status: String,
     pub title: String,
     pub description: String,
     pub outcomes: String,
@@ -46,6 +79,7 @@ impl LanceStore {
             ),
             Field::new("ticker", DataType::Utf8, false),
             Field::new("source", DataType::Utf8, false),
+            Field::new("status", DataType::Utf8, true),
             Field::new("title", DataType::Utf8, false),
             Field::new("description", DataType::Utf8, true),
             Field::new("outcomes", DataType::Utf8, true),
@@ -93,43 +127,73 @@ impl LanceStore {
     }
 
     /// Search for similar markets using a query vector.
+    ///
+    /// Thin wrapper over [`Self::search_filtered`] with no filter and the
+    /// vector column dropped from the results.
     pub async fn search(&self, query_vector: Vec<f32>, limit: usize) -> Result<Vec<MarketEmbedding>> {
+        self.search_filtered(query_vector, limit, MarketSearchFilter::default(), false)
+            .await
+    }
+
+    /// Search for similar markets using a query vector, restricted to rows matching `filter`.
+    ///
+    /// `filter` is translated into a LanceDB SQL predicate applied via `.only_if(...)`, pushing
+    /// the restriction down to the scan instead of filtering client-side. When `with_vectors` is
+    /// true, the `vector` column is decoded back into each `MarketEmbedding` so callers can do
+    /// client-side re-ranking; this is skipped by default since the embedding is rarely needed
+    /// once a match has been found.
+    pub async fn search_filtered(
+        &self,
+        query_vector: Vec<f32>,
+        limit: usize,
+        filter: MarketSearchFilter,
+        with_vectors: bool,
+    ) -> Result<Vec<MarketEmbedding>> {
         let table = self.conn.open_table(TABLE_NAME).execute().await?;
-        
+
         // Ensure query vector size matches dimension
         if query_vector.len() != VECTOR_DIM as usize {
             anyhow::bail!("Query vector dimension mismatch. Expected {}, got {}", VECTOR_DIM, query_vector.len());
         }
 
-        let results = table
-            .query()
-            .nearest_to(query_vector)?
-            .limit(limit)
-            .execute()
-            .await?
-            .try_collect::<Vec<_>>()
-            .await?;
+        let mut query = table.query().nearest_to(query_vector)?.limit(limit);
+        if let Some(predicate) = filter.to_predicate() {
+            query = query.only_if(predicate);
+        }
+
+        let results = query.execute().await?.try_collect::<Vec<_>>().await?;
 
         let mut markets = Vec::new();
 
         for batch in results {
             let ids = batch.column(0).as_any().downcast_ref::<StringArray>().unwrap();
-            // Vector column retrieval is complex due to nesting, skipping strictly for returning search results
-            // if we don't need the vector back. If we do, we need to handle FixedSizeListArray.
-            
-            // For now, let's just grab metadata columns.
+            let vectors = with_vectors
+                .then(|| batch.column(1).as_any().downcast_ref::<FixedSizeListArray>().unwrap());
             let tickers = batch.column(2).as_any().downcast_ref::<StringArray>().unwrap();
             let sources = batch.column(3).as_any().downcast_ref::<StringArray>().unwrap();
-            let titles = batch.column(4).as_any().downcast_ref::<StringArray>().unwrap();
-            let descriptions = batch.column(5).as_any().downcast_ref::<StringArray>().unwrap();
-            let outcomes = batch.column(6).as_any().downcast_ref::<StringArray>().unwrap();
+            let statuses = batch.column(4).as_any().downcast_ref::<StringArray>().unwrap();
+            let titles = batch.column(5).as_any().downcast_ref::<StringArray>().unwrap();
+            let descriptions = batch.column(6).as_any().downcast_ref::<StringArray>().unwrap();
+            let outcomes = batch.column(7).as_any().downcast_ref::<StringArray>().unwrap();
 
             for i in 0..batch.num_rows() {
+                let vector = match vectors {
+                    Some(vectors) => vectors
+                        .value(i)
+                        .as_any()
+                        .downcast_ref::<Float32Array>()
+                        .unwrap()
+                        .values()
+                        .to_vec(),
+                    None => vec![],
+                };
+
                 markets.push(MarketEmbedding {
                     id: ids.value(i).to_string(),
-                    vector: vec![], // Omitted for efficiency/simplicity in read-path
+                    vector,
                     ticker: tickers.value(i).to_string(),
                     source: sources.value(i).to_string(),
+                    status: statuses.value(i).to_string(),
                     title: titles.value(i).to_string(),
                     description: descriptions.value(i).to_string(),
                     outcomes: outcomes.value(i).to_string(),
@@ -147,6 +211,7 @@ impl LanceStore {
         let mut vector_values = Vec::with_capacity(num_rows * VECTOR_DIM as usize);
         let mut ticker_builder = Vec::with_capacity(num_rows);
         let mut source_builder = Vec::with_capacity(num_rows);
+        let mut status_builder = Vec::with_capacity(num_rows);
         let mut title_builder = Vec::with_capacity(num_rows);
         let mut description_builder = Vec::with_capacity(num_rows);
         let mut outcomes_builder = Vec::with_capacity(num_rows);
@@ -159,6 +224,7 @@ impl LanceStore {
             vector_values.extend(m.vector);
             ticker_builder.push(m.ticker);
             source_builder.push(m.source);
+            status_builder.push(m.status);
             title_builder.push(m.title);
             description_builder.push(m.description);
             outcomes_builder.push(m.outcomes);
@@ -176,6 +242,7 @@ impl LanceStore {
 
         let ticker_array = StringArray::from(ticker_builder);
         let source_array = StringArray::from(source_builder);
+        let status_array = StringArray::from(status_builder);
         let title_array = StringArray::from(title_builder);
         let description_array = StringArray::from(description_builder);
         let outcomes_array = StringArray::from(outcomes_builder);
@@ -187,6 +254,7 @@ impl LanceStore {
                 Arc::new(vector_array),
                 Arc::new(ticker_array),
                 Arc::new(source_array),
+                Arc::new(status_array),
                 Arc::new(title_array),
                 Arc::new(description_array),
                 Arc::new(outcomes_array),
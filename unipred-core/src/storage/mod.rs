@@ -0,0 +1,34 @@
+pub mod duck;
+pub mod lance;
+pub mod candles;
+pub mod trades;
+pub mod backend;
+pub mod postgres;
+
+use crate::config::{StorageBackendKind, StorageConfig};
+use crate::storage::backend::StorageBackend;
+use crate::storage::duck::DuckStore;
+use crate::storage::postgres::PgStore;
+use anyhow::{Context, Result};
+
+/// Opens the `StorageBackend` selected by `config` (`DuckDb` or `Postgres`) and initializes
+/// its schema -- the one place `IngestionEngine` goes to avoid hardcoding DuckDB, so the same
+/// ingestion pipeline can run against either backend.
+pub async fn open_backend(config: &StorageConfig) -> Result<Box<dyn StorageBackend>> {
+    let mut backend: Box<dyn StorageBackend> = match config.backend {
+        StorageBackendKind::DuckDb => {
+            let path = config.duckdb_path.as_deref().unwrap_or("unipred.duckdb");
+            Box::new(DuckStore::open(path).context("Failed to open DuckDB store")?)
+        }
+        StorageBackendKind::Postgres => {
+            let env_var = config.postgres_env_var.as_deref().unwrap_or("DATABASE_URL");
+            Box::new(
+                PgStore::connect_from_env(env_var)
+                    .await
+                    .context("Failed to connect to Postgres store")?,
+            )
+        }
+    };
+    backend.init_schema().await?;
+    Ok(backend)
+}
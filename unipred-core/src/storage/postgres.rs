@@ -0,0 +1,294 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use std::collections::HashSet;
+use tokio_postgres::{Client, NoTls, Transaction};
+
+use crate::proto::{FetchedEvent, FetchedMarket};
+use crate::storage::backend::StorageBackend;
+
+/// `StorageBackend` implementation backed by Postgres, for shared ingestion services where
+/// a local DuckDB file isn't viable. Connection string is read from the environment so
+/// deployments don't hardcode credentials into config files.
+pub struct PgStore {
+    client: Client,
+}
+
+impl PgStore {
+    /// Connect using a `postgres://` connection string read from `env_var` (e.g. `DATABASE_URL`).
+    pub async fn connect_from_env(env_var: &str) -> Result<Self> {
+        let conn_str = std::env::var(env_var)
+            .with_context(|| format!("{} must be set to a postgres:// connection string", env_var))?;
+        Self::connect(&conn_str).await
+    }
+
+    pub async fn connect(conn_str: &str) -> Result<Self> {
+        let (client, connection) = tokio_postgres::connect(conn_str, NoTls)
+            .await
+            .context("Failed to connect to Postgres")?;
+
+        // The connection object drives IO in the background; if it dies we just lose the
+        // ability to query, which will surface as errors on the next call.
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                eprintln!("Postgres connection error: {}", e);
+            }
+        });
+
+        Ok(Self { client })
+    }
+}
+
+#[async_trait]
+impl StorageBackend for PgStore {
+    async fn init_schema(&mut self) -> Result<()> {
+        self.client
+            .batch_execute(
+                "CREATE TABLE IF NOT EXISTS markets (
+                    ticker TEXT,
+                    source TEXT,
+                    title TEXT,
+                    status TEXT,
+                    description TEXT,
+                    outcomes TEXT,
+                    start_date TEXT,
+                    end_date TEXT,
+                    volume TEXT,
+                    liquidity TEXT,
+                    url TEXT,
+                    content_hash TEXT,
+                    ingested_at TIMESTAMPTZ DEFAULT now(),
+                    PRIMARY KEY (ticker, source)
+                );
+
+                CREATE TABLE IF NOT EXISTS events (
+                    ticker TEXT,
+                    source TEXT,
+                    title TEXT,
+                    description TEXT,
+                    start_date TEXT,
+                    end_date TEXT,
+                    url TEXT,
+                    ingested_at TIMESTAMPTZ DEFAULT now(),
+                    PRIMARY KEY (ticker, source)
+                );
+
+                CREATE TABLE IF NOT EXISTS ingestion_checkpoints (
+                    exchange TEXT,
+                    kind TEXT,
+                    status TEXT,
+                    cursor TEXT,
+                    page_count BIGINT,
+                    updated_at TIMESTAMPTZ DEFAULT now(),
+                    PRIMARY KEY (exchange, kind, status)
+                );",
+            )
+            .await
+            .context("Failed to initialize Postgres schema")?;
+        Ok(())
+    }
+
+    async fn insert_batch(&mut self, markets: &[FetchedMarket]) -> Result<()> {
+        if markets.is_empty() {
+            return Ok(());
+        }
+        let tx = self.client.transaction().await?;
+        Self::insert_markets_tx(&tx, markets).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn insert_events_batch(&mut self, events: &[FetchedEvent]) -> Result<()> {
+        if events.is_empty() {
+            return Ok(());
+        }
+        let tx = self.client.transaction().await?;
+        Self::insert_events_tx(&tx, events).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn insert_batch_and_checkpoint(
+        &mut self,
+        markets: &[FetchedMarket],
+        exchange: &str,
+        status: &str,
+        cursor: &str,
+        page_count: i64,
+    ) -> Result<()> {
+        if markets.is_empty() {
+            return Ok(());
+        }
+        let tx = self.client.transaction().await?;
+        Self::insert_markets_tx(&tx, markets).await?;
+        Self::save_checkpoint_tx(&tx, exchange, "markets", status, cursor, page_count).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn insert_events_batch_and_checkpoint(
+        &mut self,
+        events: &[FetchedEvent],
+        exchange: &str,
+        status: &str,
+        cursor: &str,
+        page_count: i64,
+    ) -> Result<()> {
+        if events.is_empty() {
+            return Ok(());
+        }
+        let tx = self.client.transaction().await?;
+        Self::insert_events_tx(&tx, events).await?;
+        Self::save_checkpoint_tx(&tx, exchange, "events", status, cursor, page_count).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn load_checkpoint(&mut self, exchange: &str, kind: &str, status: &str) -> Result<Option<(String, i64)>> {
+        let row = self
+            .client
+            .query_opt(
+                "SELECT cursor, page_count FROM ingestion_checkpoints
+                 WHERE exchange = $1 AND kind = $2 AND status = $3",
+                &[&exchange, &kind, &status],
+            )
+            .await?;
+        Ok(row.map(|r| (r.get(0), r.get(1))))
+    }
+
+    async fn clear_checkpoint(&mut self, exchange: &str, kind: &str, status: &str) -> Result<()> {
+        self.client
+            .execute(
+                "DELETE FROM ingestion_checkpoints WHERE exchange = $1 AND kind = $2 AND status = $3",
+                &[&exchange, &kind, &status],
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn changed_content_tickers(&mut self, markets: &[FetchedMarket]) -> Result<HashSet<(String, String)>> {
+        let stmt = self
+            .client
+            .prepare(
+                "SELECT 1 FROM markets WHERE ticker = $1 AND source = $2 AND content_hash = md5($3 || '|' || $4)",
+            )
+            .await?;
+
+        let mut changed = HashSet::new();
+        for m in markets {
+            let rows = self
+                .client
+                .query(&stmt, &[&m.ticker, &m.source, &m.title, &m.description])
+                .await?;
+            if rows.is_empty() {
+                changed.insert((m.ticker.clone(), m.source.clone()));
+            }
+        }
+        Ok(changed)
+    }
+}
+
+impl PgStore {
+    /// Upsert `markets` within `tx`, without committing -- shared by `insert_batch` and
+    /// `insert_batch_and_checkpoint` so the latter can fold a checkpoint write into the same
+    /// transaction, mirroring `DuckStore::merge_markets_staging`.
+    async fn insert_markets_tx(tx: &Transaction<'_>, markets: &[FetchedMarket]) -> Result<()> {
+        let stmt = tx
+            .prepare(
+                "INSERT INTO markets (
+                    ticker, source, title, status, description, outcomes,
+                    start_date, end_date, volume, liquidity, url, content_hash
+                ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, md5($3 || '|' || $5))
+                ON CONFLICT (ticker, source) DO UPDATE SET
+                    title = excluded.title,
+                    status = excluded.status,
+                    description = excluded.description,
+                    outcomes = excluded.outcomes,
+                    start_date = excluded.start_date,
+                    end_date = excluded.end_date,
+                    volume = excluded.volume,
+                    liquidity = excluded.liquidity,
+                    url = excluded.url,
+                    content_hash = excluded.content_hash",
+            )
+            .await?;
+
+        for m in markets {
+            let outcomes_str = m.outcomes.join(", ");
+            tx.execute(
+                &stmt,
+                &[
+                    &m.ticker,
+                    &m.source,
+                    &m.title,
+                    &m.status,
+                    &m.description,
+                    &outcomes_str,
+                    &m.start_date,
+                    &m.end_date,
+                    &m.volume,
+                    &m.liquidity,
+                    &m.url,
+                ],
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// Upsert `events` within `tx`, without committing; see `insert_markets_tx`.
+    async fn insert_events_tx(tx: &Transaction<'_>, events: &[FetchedEvent]) -> Result<()> {
+        let stmt = tx
+            .prepare(
+                "INSERT INTO events (
+                    ticker, source, title, description, start_date, end_date, url
+                ) VALUES ($1, $2, $3, $4, $5, $6, $7)
+                ON CONFLICT (ticker, source) DO UPDATE SET
+                    title = excluded.title,
+                    description = excluded.description,
+                    start_date = excluded.start_date,
+                    end_date = excluded.end_date,
+                    url = excluded.url",
+            )
+            .await?;
+
+        for e in events {
+            tx.execute(
+                &stmt,
+                &[
+                    &e.ticker,
+                    &e.source,
+                    &e.title,
+                    &e.description,
+                    &e.start_date,
+                    &e.end_date,
+                    &e.url,
+                ],
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// Upsert the ingestion checkpoint for `(exchange, kind, status)` within `tx`; see
+    /// `DuckStore::save_checkpoint_tx`.
+    async fn save_checkpoint_tx(
+        tx: &Transaction<'_>,
+        exchange: &str,
+        kind: &str,
+        status: &str,
+        cursor: &str,
+        page_count: i64,
+    ) -> Result<()> {
+        tx.execute(
+            "INSERT INTO ingestion_checkpoints (exchange, kind, status, cursor, page_count)
+             VALUES ($1, $2, $3, $4, $5)
+             ON CONFLICT (exchange, kind, status) DO UPDATE SET
+                cursor = excluded.cursor,
+                page_count = excluded.page_count,
+                updated_at = now()",
+            &[&exchange, &kind, &status, &cursor, &page_count],
+        )
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+use crate::clients::kalshi::tick::Tick;
+
+/// A single executed trade as reported by an exchange, keyed for idempotent backfill
+/// by `(ticker, source, trade_id)`. `traded_at` is always the exchange-reported execution
+/// time (epoch seconds) rather than ingest time, so a stale/late backfill still lands
+/// trades in the correct candle bucket.
+#[derive(Debug, Clone)]
+pub struct RawTrade {
+    pub ticker: String,
+    pub source: String,
+    pub trade_id: String,
+    pub price: Tick,
+    pub size: i32,
+    pub side: String,
+    pub traded_at: i64,
+}
@@ -1,50 +1,22 @@
 use std::env;
-use std::fs;
+use unipred_core::config::Config;
 use unipred_core::domain::MarketSource;
+use unipred_core::error::UnipredError;
 use unipred_core::UnipredCore;
 
 // Helper to setup authenticated core
 async fn setup_core() -> UnipredCore {
-    // Manually parse .env because rust-dotenv struggles with multiline strings
-    let env_paths = vec![".env", "../.env"];
-    for path in env_paths {
-        let p = std::path::Path::new(path);
-        if p.exists() {
-            if let Ok(content) = fs::read_to_string(p) {
-                // Parse KALSHI_API_KEY_ID
-                if env::var("KALSHI_API_KEY_ID").is_err() {
-                    for line in content.lines() {
-                        if line.trim().starts_with("KALSHI_API_KEY_ID=") {
-                            let val = line.trim()
-                                .trim_start_matches("KALSHI_API_KEY_ID=")
-                                .trim_matches('"');
-                            env::set_var("KALSHI_API_KEY_ID", val);
-                            break;
-                        }
-                    }
-                }
-
-                // Parse KALSHI_PRIVATE_KEY (multiline support)
-                if env::var("KALSHI_PRIVATE_KEY").is_err() {
-                    let key_marker = "KALSHI_PRIVATE_KEY=\"";
-                    if let Some(start_idx) = content.find(key_marker) {
-                        let rest = &content[start_idx + key_marker.len()..];
-                        if let Some(end_idx) = rest.find('"') {
-                            let val = &rest[..end_idx];
-                            env::set_var("KALSHI_PRIVATE_KEY", val);
-                        }
-                    }
-                }
-            }
-        }
-    }
+    // `Config::load_env_for_tests` shares the `.env` loader behind `Config::from_env_and_file`,
+    // which handles the multiline `KALSHI_PRIVATE_KEY` PEM that rust-dotenv struggles with;
+    // this used to be hand-rolled here.
+    Config::load_env_for_tests(&[".env", "../.env"]);
 
     let key_id = env::var("KALSHI_API_KEY_ID").expect("KALSHI_API_KEY_ID must be set");
     let private_key = env::var("KALSHI_PRIVATE_KEY").expect("KALSHI_PRIVATE_KEY must be set");
 
-    let mut core = UnipredCore::new("".to_string());
+    let mut core = UnipredCore::new_unconfigured().expect("Failed to build unconfigured core");
     core.kalshi
-        .login_apikey(&key_id, &private_key) // Using string content method for simplicity if available, or path
+        .login_apikey_from_pem(&key_id, &private_key)
         .await
         .expect("Login failed");
 
@@ -167,14 +139,11 @@ async fn test_get_quote_polymarket() {
             assert_eq!(q.source, "Polymarket");
             assert!(!q.price.is_empty());
         }
-        Err(e) => {
-            // Allow "No orderbook exists" error as success for integration plumbing check
-            // similar to Python test logic if market is stale
-            let err_str = e.to_string();
-            if !err_str.contains("No orderbook exists") {
-                panic!("Unexpected error: {}", err_str);
-            }
+        Err(UnipredError::NoOrderbook { .. }) => {
+            // Allow this as success for integration plumbing check, similar to the Python
+            // test logic, if the market is stale and has no book.
         }
+        Err(e) => panic!("Unexpected error: {}", e),
     }
 }
 
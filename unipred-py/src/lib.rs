@@ -1,10 +1,42 @@
 use prost::Message;
+use pyo3::create_exception;
 use pyo3::prelude::*;
 use pyo3::types::PyBytes;
 use unipred_core::UnipredCore as CoreUnipred;
 use unipred_core::commands::quote::GetMarketQuote;
 use unipred_core::commands::markets::FetchMarkets;
+use unipred_core::commands::trading::{CancelOrder, GetOpenOrders, GetPositions, PlaceOrder};
 use unipred_core::commands::Command;
+use unipred_core::domain::{OrderSide, OrderType, TimeInForce};
+use unipred_core::error::UnipredError;
+use unipred_core::clients::kalshi::tick::Tick;
+
+create_exception!(unipred_py, UnipredAuthError, pyo3::exceptions::PyException);
+create_exception!(unipred_py, UnipredRateLimited, pyo3::exceptions::PyException);
+create_exception!(unipred_py, UnipredNotFound, pyo3::exceptions::PyException);
+create_exception!(unipred_py, UnipredNoOrderbook, pyo3::exceptions::PyException);
+create_exception!(unipred_py, UnipredUpstreamError, pyo3::exceptions::PyException);
+create_exception!(unipred_py, UnipredParseError, pyo3::exceptions::PyException);
+
+/// Map a typed `UnipredError` onto a distinct Python exception subclass so callers can
+/// `except UnipredNoOrderbook` instead of string-matching `PyRuntimeError`'s message.
+fn map_unipred_err(e: UnipredError) -> PyErr {
+    match e {
+        UnipredError::Auth(msg) => UnipredAuthError::new_err(msg),
+        UnipredError::RateLimited { retry_after } => {
+            UnipredRateLimited::new_err(format!("rate limited (retry_after={:?}s)", retry_after))
+        }
+        UnipredError::NotFound(msg) => UnipredNotFound::new_err(msg),
+        UnipredError::NoOrderbook { ticker } => {
+            UnipredNoOrderbook::new_err(format!("no orderbook exists for ticker {}", ticker))
+        }
+        UnipredError::Upstream { source, status } => {
+            UnipredUpstreamError::new_err(format!("upstream error from {}: HTTP {}", source, status))
+        }
+        UnipredError::Parse(msg) => UnipredParseError::new_err(msg),
+        UnipredError::Other(err) => pyo3::exceptions::PyRuntimeError::new_err(err.to_string()),
+    }
+}
 
 #[pyclass]
 struct UnipredCore {
@@ -15,11 +47,14 @@ struct UnipredCore {
 #[pymethods]
 impl UnipredCore {
     #[new]
-    fn new(config: String) -> Self {
-        UnipredCore {
-            inner: CoreUnipred::new(config),
+    fn new(config_path: String) -> PyResult<Self> {
+        let inner = CoreUnipred::new(config_path)
+            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+        Ok(UnipredCore {
+            inner,
             rt: tokio::runtime::Runtime::new().unwrap(),
-        }
+        })
     }
 
     fn login(&mut self, email: String, password: String) -> PyResult<()> {
@@ -82,7 +117,120 @@ impl UnipredCore {
                     .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
                 Python::with_gil(|py| Ok(PyBytes::new(py, &buf).into()))
             }
-            Err(e) => Err(pyo3::exceptions::PyRuntimeError::new_err(e.to_string())),
+            Err(e) => Err(map_unipred_err(e)),
+        }
+    }
+
+    #[pyo3(signature = (ticker, side, price, size, exchange=None, order_type="limit", tif="gtc"))]
+    fn _place_order_bytes(
+        &self,
+        ticker: String,
+        side: String,
+        price: String,
+        size: i64,
+        exchange: Option<String>,
+        order_type: &str,
+        tif: &str,
+    ) -> PyResult<Py<PyBytes>> {
+        let source = match exchange.as_deref() {
+            Some("kalshi") => Some(unipred_core::domain::MarketSource::Kalshi),
+            Some("polymarket") => Some(unipred_core::domain::MarketSource::Polymarket),
+            Some(s) => return Err(pyo3::exceptions::PyValueError::new_err(format!("Unknown exchange: {}", s))),
+            None => None,
+        };
+        let side = match side.as_str() {
+            "yes" => OrderSide::Yes,
+            "no" => OrderSide::No,
+            s => return Err(pyo3::exceptions::PyValueError::new_err(format!("Unknown side: {}", s))),
+        };
+        let order_type = match order_type {
+            "limit" => OrderType::Limit,
+            "market" => OrderType::Market,
+            s => return Err(pyo3::exceptions::PyValueError::new_err(format!("Unknown order_type: {}", s))),
+        };
+        let tif = match tif {
+            "gtc" => TimeInForce::GoodTilCanceled,
+            "ioc" => TimeInForce::ImmediateOrCancel,
+            "fok" => TimeInForce::FillOrKill,
+            s => return Err(pyo3::exceptions::PyValueError::new_err(format!("Unknown tif: {}", s))),
+        };
+        let price = Tick::from_dollars_round_down(&price)
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(format!("Invalid price: {}", price)))?;
+
+        let cmd = PlaceOrder::new(ticker, side, price, size)
+            .with_exchange(source)
+            .with_order_type(order_type)
+            .with_tif(tif);
+
+        let result = self.rt.block_on(async { cmd.execute(&self.inner).await });
+
+        match result {
+            Ok(order) => {
+                let mut buf = Vec::new();
+                order.encode(&mut buf).map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+                Python::with_gil(|py| Ok(PyBytes::new(py, &buf).into()))
+            }
+            Err(e) => Err(map_unipred_err(e)),
+        }
+    }
+
+    fn cancel_order(&self, order_id: String, exchange: String) -> PyResult<()> {
+        let source = match exchange.as_str() {
+            "kalshi" => unipred_core::domain::MarketSource::Kalshi,
+            "polymarket" => unipred_core::domain::MarketSource::Polymarket,
+            s => return Err(pyo3::exceptions::PyValueError::new_err(format!("Unknown exchange: {}", s))),
+        };
+
+        let cmd = CancelOrder::new(order_id, source);
+        let result = self.rt.block_on(async { cmd.execute(&self.inner).await });
+        result.map_err(map_unipred_err)
+    }
+
+    #[pyo3(signature = (exchange=None, ticker=None))]
+    fn _get_open_orders_bytes(&self, exchange: Option<String>, ticker: Option<String>) -> PyResult<Py<PyBytes>> {
+        let source = match exchange.as_deref() {
+            Some("kalshi") => Some(unipred_core::domain::MarketSource::Kalshi),
+            Some("polymarket") => Some(unipred_core::domain::MarketSource::Polymarket),
+            Some(s) => return Err(pyo3::exceptions::PyValueError::new_err(format!("Unknown exchange: {}", s))),
+            None => None,
+        };
+
+        let mut cmd = GetOpenOrders::new().with_exchange(source);
+        if let Some(t) = ticker {
+            cmd = cmd.with_ticker(t);
+        }
+
+        let result = self.rt.block_on(async { cmd.execute(&self.inner).await });
+
+        match result {
+            Ok(orders) => {
+                let mut buf = Vec::new();
+                orders.encode(&mut buf).map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+                Python::with_gil(|py| Ok(PyBytes::new(py, &buf).into()))
+            }
+            Err(e) => Err(map_unipred_err(e)),
+        }
+    }
+
+    #[pyo3(signature = (exchange=None))]
+    fn _get_positions_bytes(&self, exchange: Option<String>) -> PyResult<Py<PyBytes>> {
+        let source = match exchange.as_deref() {
+            Some("kalshi") => Some(unipred_core::domain::MarketSource::Kalshi),
+            Some("polymarket") => Some(unipred_core::domain::MarketSource::Polymarket),
+            Some(s) => return Err(pyo3::exceptions::PyValueError::new_err(format!("Unknown exchange: {}", s))),
+            None => None,
+        };
+
+        let cmd = GetPositions::new().with_exchange(source);
+        let result = self.rt.block_on(async { cmd.execute(&self.inner).await });
+
+        match result {
+            Ok(positions) => {
+                let mut buf = Vec::new();
+                positions.encode(&mut buf).map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+                Python::with_gil(|py| Ok(PyBytes::new(py, &buf).into()))
+            }
+            Err(e) => Err(map_unipred_err(e)),
         }
     }
 
@@ -108,7 +256,7 @@ impl UnipredCore {
                 quote.encode(&mut buf).map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
 Python::with_gil(|py| Ok(PyBytes::new(py, &buf).into()))
             },
-            Err(e) => Err(pyo3::exceptions::PyRuntimeError::new_err(e.to_string())),
+            Err(e) => Err(map_unipred_err(e)),
         }
     }
 }
@@ -117,5 +265,14 @@ Python::with_gil(|py| Ok(PyBytes::new(py, &buf).into()))
 #[pymodule]
 fn unipred_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<UnipredCore>()?;
+
+    let py = m.py();
+    m.add("UnipredAuthError", py.get_type::<UnipredAuthError>())?;
+    m.add("UnipredRateLimited", py.get_type::<UnipredRateLimited>())?;
+    m.add("UnipredNotFound", py.get_type::<UnipredNotFound>())?;
+    m.add("UnipredNoOrderbook", py.get_type::<UnipredNoOrderbook>())?;
+    m.add("UnipredUpstreamError", py.get_type::<UnipredUpstreamError>())?;
+    m.add("UnipredParseError", py.get_type::<UnipredParseError>())?;
+
     Ok(())
 }